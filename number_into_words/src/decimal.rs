@@ -0,0 +1,235 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::digits::encode_abs_digits;
+use crate::{ParseError, ParseErrorKind, ONES};
+
+/// Decimal number kept as its digits, so nothing is lost to binary floating
+/// point. Parse it from a string like "-3.14", or convert an `f64`, which
+/// takes the shortest digits that read back as the same `f64`, so `0.1` is
+/// "0.1" and not the slightly larger value it is stored as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decimal {
+    negative: bool,
+    // no leading zeros, empty means zero
+    integer: String,
+    fraction: String,
+}
+
+impl Decimal {
+    /// Rounds half away from zero to at most `places` digits after the point.
+    /// Numbers that are already short enough are not padded.
+    pub fn round(&self, places: usize) -> Decimal {
+        let Some(&next) = self.fraction.as_bytes().get(places) else {
+            return self.clone();
+        };
+
+        let mut digits = [self.integer.as_bytes(), &self.fraction.as_bytes()[..places]].concat();
+        if next >= b'5' {
+            // add one to the last kept digit, carrying over the nines
+            match digits.iter().rposition(|digit| *digit != b'9') {
+                Some(i) => {
+                    digits[i] += 1;
+                    digits[i + 1..].fill(b'0');
+                }
+                None => {
+                    digits.fill(b'0');
+                    digits.insert(0, b'1');
+                }
+            }
+        }
+
+        let split = digits.len() - places;
+        let digits = String::from_utf8(digits).unwrap();
+
+        Decimal {
+            negative: self.negative,
+            integer: digits[..split].trim_start_matches('0').to_string(),
+            fraction: digits[split..].to_string(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.integer.is_empty() && self.fraction.bytes().all(|digit| digit == b'0')
+    }
+
+    fn sign(&self) -> &'static str {
+        match self.negative && !self.is_zero() {
+            true => "minus ",
+            false => "",
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (negative, number) = match input.strip_prefix('-') {
+            Some(number) => (true, number),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(ParseError {
+                kind: ParseErrorKind::Empty,
+                span: 0..input.len(),
+            });
+        }
+
+        let offset = input.len() - number.len();
+        let invalid = number
+            .char_indices()
+            .filter(|(i, _)| *i != integer.len())
+            .find(|(_, c)| !c.is_ascii_digit());
+        if let Some((i, c)) = invalid {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidDigit,
+                span: offset + i..offset + i + c.len_utf8(),
+            });
+        }
+
+        Ok(Decimal {
+            negative,
+            integer: integer.trim_start_matches('0').to_string(),
+            fraction: fraction.to_string(),
+        })
+    }
+}
+
+impl TryFrom<f64> for Decimal {
+    type Error = ParseError;
+
+    /// Fails for NaN and the infinities.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        value.to_string().parse()
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.negative && !self.is_zero() {
+            "-"
+        } else {
+            ""
+        };
+        let integer = if self.integer.is_empty() {
+            "0"
+        } else {
+            &self.integer
+        };
+        write!(f, "{}{}", sign, integer)?;
+
+        match self.fraction.is_empty() {
+            true => Ok(()),
+            false => write!(f, ".{}", self.fraction),
+        }
+    }
+}
+
+/// "three point one four one". Every digit after the point is read on its
+/// own, including trailing zeros, so round first to control how many there
+/// are.
+pub fn encode_decimal(num: &Decimal) -> String {
+    let mut words = format!("{}{}", num.sign(), encode_abs_digits(&num.integer));
+
+    if !num.fraction.is_empty() {
+        words.push_str(" point");
+        for digit in num.fraction.bytes() {
+            words.push(' ');
+            words.push_str(ONES[(digit - b'0') as usize]);
+        }
+    }
+
+    words
+}
+
+/// Cheque style, with the cents as digits: "twenty-five and 07/100".
+pub fn encode_cheque(num: &Decimal) -> String {
+    let num = num.round(2);
+
+    format!(
+        "{}{} and {:0<2}/100",
+        num.sign(),
+        encode_abs_digits(&num.integer),
+        num.fraction
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_cheque, encode_decimal, Decimal};
+    use crate::{ParseError, ParseErrorKind};
+
+    fn decimal(input: &str) -> Decimal {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn reads_digits_after_the_point() {
+        let cases = [
+            ("3.141", "three point one four one"),
+            ("-0.50", "minus zero point five zero"),
+            (".5", "zero point five"),
+            ("12.", "twelve"),
+            ("-0.0", "zero point zero"),
+        ];
+
+        for (input, words) in cases {
+            assert_eq!(encode_decimal(&decimal(input)), words);
+        }
+
+        let sum = Decimal::try_from(0.1 + 0.2).unwrap();
+        assert_eq!(sum.to_string(), "0.30000000000000004");
+        assert_eq!(encode_decimal(&sum.round(3)), "zero point three zero zero");
+        assert_eq!(
+            encode_decimal(&Decimal::try_from(0.1).unwrap()),
+            "zero point one"
+        );
+        assert!(Decimal::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn rounds_half_away_from_zero() {
+        let cases = [
+            ("2.345", 2, "2.35"),
+            ("2.344", 2, "2.34"),
+            ("-2.345", 2, "-2.35"),
+            ("9.996", 2, "10.00"),
+            ("0.96", 1, "1.0"),
+            ("199.5", 0, "200"),
+            ("1.5", 3, "1.5"),
+        ];
+
+        for (input, places, rounded) in cases {
+            assert_eq!(decimal(input).round(places).to_string(), rounded);
+        }
+    }
+
+    #[test]
+    fn cheque_style() {
+        assert_eq!(
+            encode_cheque(&decimal("1234.5")),
+            "one thousand two hundred thirty-four and 50/100"
+        );
+        assert_eq!(encode_cheque(&decimal("25.074")), "twenty-five and 07/100");
+        assert_eq!(encode_cheque(&decimal("-0.001")), "zero and 00/100");
+        assert_eq!(encode_cheque(&decimal("-7")), "minus seven and 00/100");
+    }
+
+    #[test]
+    fn rejects_anything_but_a_decimal() {
+        let error = |kind, span| Err(ParseError { kind, span });
+
+        assert_eq!("-.".parse::<Decimal>(), error(ParseErrorKind::Empty, 0..2));
+        assert_eq!(
+            "1.2.3".parse::<Decimal>(),
+            error(ParseErrorKind::InvalidDigit, 3..4)
+        );
+        assert_eq!(
+            "1,5".parse::<Decimal>(),
+            error(ParseErrorKind::InvalidDigit, 1..2)
+        );
+    }
+}
@@ -0,0 +1,365 @@
+use std::fmt::Display;
+use std::ops::Range;
+
+use crate::{Integer, ONES, ORDERS, TENS};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// Byte range of the offending word or character in the input.
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input has no words.
+    Empty,
+    /// A word that is not part of a number.
+    UnknownWord,
+    /// A number word in a place where it makes no sense, e.g. "twenty ten"
+    /// or "one thousand million".
+    UnexpectedWord,
+    /// The number does not fit into the requested type, a `u64` for
+    /// [`decode`].
+    Overflow,
+    /// A character that is not a decimal digit, from
+    /// [`encode_digits`](crate::encode_digits) or parsing a
+    /// [`Decimal`](crate::Decimal).
+    InvalidDigit,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self.kind {
+            ParseErrorKind::Empty => "no number",
+            ParseErrorKind::UnknownWord => "unknown word",
+            ParseErrorKind::UnexpectedWord => "unexpected word",
+            ParseErrorKind::Overflow => "number is too large",
+            ParseErrorKind::InvalidDigit => "invalid digit",
+        };
+
+        write!(f, "{} at {}..{}", message, self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Word {
+    Zero,
+    // 1..=19
+    Ones(u128),
+    // 20, 30, ..., 90
+    Tens(u128),
+    Hundred,
+    // index into `ORDERS`
+    Order(usize),
+    And,
+    Minus,
+}
+
+/// Parses numbers written the way [`encode`](crate::encode) writes a `u64`.
+/// Case, extra whitespace, commas, "and" and any kind of hyphen between
+/// words are tolerated, so "One Hundred and Twenty One" works too. See
+/// [`decode_as`] for other integer types, including negative numbers.
+pub fn decode(input: &str) -> Result<u64, ParseError> {
+    decode_as(input)
+}
+
+/// Like [`decode`], but into any integer type, so it reads back everything
+/// [`encode`](crate::encode) writes, e.g. "minus five" as an `i32`.
+pub fn decode_as<N: Integer>(input: &str) -> Result<N, ParseError> {
+    let words = split(input)
+        .map(|span| {
+            let word = &input[span.clone()];
+            match lookup(word) {
+                Some(word) => Ok((word, span)),
+                None => Err(ParseError {
+                    kind: ParseErrorKind::UnknownWord,
+                    span,
+                }),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if words.is_empty() {
+        return Err(ParseError {
+            kind: ParseErrorKind::Empty,
+            span: 0..input.len(),
+        });
+    }
+
+    let (negative, words) = match words.split_first() {
+        Some(((Word::Minus, span), [])) => {
+            return Err(ParseError {
+                kind: ParseErrorKind::UnexpectedWord,
+                span: span.clone(),
+            })
+        }
+        Some(((Word::Minus, _), rest)) => (true, rest),
+        _ => (false, words.as_slice()),
+    };
+    // partial totals only grow, so every one of them has to fit
+    let fits = |value: u128| N::from_sign(negative, value).is_some();
+
+    if let [(Word::Zero, _)] = words {
+        return Ok(N::from_sign(negative, 0).unwrap());
+    }
+
+    let mut total = 0_u128;
+    let mut group = Group::default();
+    let mut last_order = None;
+    // where the words of the current group start
+    let mut group_start = 0;
+
+    for (i, (word, span)) in words.iter().enumerate() {
+        let error = |kind| ParseError {
+            kind,
+            span: span.clone(),
+        };
+        let unexpected = || error(ParseErrorKind::UnexpectedWord);
+
+        match *word {
+            Word::Zero | Word::Minus => return Err(unexpected()),
+            Word::Ones(n) => {
+                let fits = group.units.is_none() && (n < 10 || group.tens.is_none());
+                if !fits {
+                    return Err(unexpected());
+                }
+                group.units = Some(n);
+            }
+            Word::Tens(n) => {
+                if group.tens.is_some() || group.units.is_some() {
+                    return Err(unexpected());
+                }
+                group.tens = Some(n);
+            }
+            Word::Hundred => match (group.hundreds, group.tens, group.units) {
+                (None, None, Some(n)) if n < 10 => {
+                    group.hundreds = Some(n * 100);
+                    group.units = None;
+                }
+                _ => return Err(unexpected()),
+            },
+            Word::Order(order) => {
+                let value = group.value();
+                if value == 0 || last_order.is_some_and(|last| order >= last) {
+                    return Err(unexpected());
+                }
+
+                total = 1000_u128
+                    .checked_pow(order as u32)
+                    .and_then(|order_value| value.checked_mul(order_value))
+                    .and_then(|value| total.checked_add(value))
+                    .filter(|total| fits(*total))
+                    .ok_or_else(|| error(ParseErrorKind::Overflow))?;
+
+                group = Group::default();
+                last_order = Some(order);
+                group_start = span.end;
+            }
+            Word::And => {
+                let previous = i.checked_sub(1).map(|i| words[i].0);
+                let follows = matches!(previous, Some(Word::Hundred | Word::Order(_)));
+                let is_last = i == words.len() - 1;
+                if !follows || is_last {
+                    return Err(unexpected());
+                }
+            }
+        }
+    }
+
+    let total = total
+        .checked_add(group.value())
+        .filter(|total| fits(*total));
+
+    total
+        .and_then(|total| N::from_sign(negative, total))
+        .ok_or_else(|| {
+            let end = words.last().map_or(input.len(), |(_, span)| span.end);
+            let start = words
+                .iter()
+                .map(|(_, span)| span.start)
+                .find(|start| *start >= group_start)
+                .unwrap_or(end);
+
+            ParseError {
+                kind: ParseErrorKind::Overflow,
+                span: start..end,
+            }
+        })
+}
+
+/// Words of one group of three digits.
+#[derive(Default)]
+struct Group {
+    hundreds: Option<u128>,
+    tens: Option<u128>,
+    units: Option<u128>,
+}
+
+impl Group {
+    fn value(&self) -> u128 {
+        self.hundreds.unwrap_or(0) + self.tens.unwrap_or(0) + self.units.unwrap_or(0)
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            ',' | '-' | '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}'
+        )
+}
+
+/// Byte ranges of the words in `input`.
+fn split(input: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut rest = input.char_indices().peekable();
+
+    std::iter::from_fn(move || {
+        while rest.next_if(|(_, c)| is_separator(*c)).is_some() {}
+
+        let (start, _) = *rest.peek()?;
+        let mut end = start;
+        while let Some((i, c)) = rest.next_if(|(_, c)| !is_separator(*c)) {
+            end = i + c.len_utf8();
+        }
+
+        Some(start..end)
+    })
+}
+
+fn lookup(word: &str) -> Option<Word> {
+    let is = |other: &str| word.eq_ignore_ascii_case(other);
+
+    if is("zero") {
+        return Some(Word::Zero);
+    }
+    if is("hundred") {
+        return Some(Word::Hundred);
+    }
+    if is("and") {
+        return Some(Word::And);
+    }
+    if is("minus") {
+        return Some(Word::Minus);
+    }
+
+    if let Some(n) = (1..ONES.len()).find(|n| is(ONES[*n])) {
+        return Some(Word::Ones(n as u128));
+    }
+    if let Some(n) = (2..TENS.len()).find(|n| is(TENS[*n])) {
+        return Some(Word::Tens(n as u128 * 10));
+    }
+    (1..ORDERS.len()).find(|n| is(ORDERS[*n])).map(Word::Order)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{decode, decode_as, ParseError, ParseErrorKind};
+    use crate::encode;
+
+    fn error(kind: ParseErrorKind, span: std::ops::Range<usize>) -> Result<u64, ParseError> {
+        Err(ParseError { kind, span })
+    }
+
+    #[test]
+    fn tolerates_spelling_variants() {
+        assert_eq!(decode("zero"), Ok(0));
+        assert_eq!(decode("  Twenty\u{2013}one "), Ok(21));
+        assert_eq!(decode("one hundred and five"), Ok(105));
+        assert_eq!(decode("ONE THOUSAND, AND TWELVE"), Ok(1012));
+        assert_eq!(
+            decode("eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four trillion seventy-three billion seven hundred nine million five hundred fifty-one thousand six hundred fifteen"),
+            Ok(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn reports_the_offending_word() {
+        assert_eq!(decode("  "), error(ParseErrorKind::Empty, 0..2));
+        assert_eq!(
+            decode("twenty-won"),
+            error(ParseErrorKind::UnknownWord, 7..10)
+        );
+        assert_eq!(
+            decode("twenty ten"),
+            error(ParseErrorKind::UnexpectedWord, 7..10)
+        );
+        assert_eq!(
+            decode("one thousand million"),
+            error(ParseErrorKind::UnexpectedWord, 13..20)
+        );
+        assert_eq!(
+            decode("one hundred and"),
+            error(ParseErrorKind::UnexpectedWord, 12..15)
+        );
+        assert_eq!(
+            decode("nineteen quintillion"),
+            error(ParseErrorKind::Overflow, 9..20)
+        );
+        assert_eq!(
+            decode("one sextillion"),
+            error(ParseErrorKind::Overflow, 4..14)
+        );
+        assert_eq!(
+            decode(&encode(u64::MAX).replace("fifteen", "sixteen")),
+            error(ParseErrorKind::Overflow, 171..190)
+        );
+    }
+
+    #[test]
+    fn decodes_into_the_requested_type() {
+        assert_eq!(decode_as::<i32>("minus five"), Ok(-5));
+        assert_eq!(
+            decode_as::<i8>("minus one hundred twenty-eight"),
+            Ok(i8::MIN)
+        );
+        assert_eq!(
+            decode_as::<i8>("one hundred twenty-eight"),
+            Err(ParseError {
+                kind: ParseErrorKind::Overflow,
+                span: 0..24
+            })
+        );
+        assert_eq!(
+            decode_as::<u8>("minus one"),
+            Err(ParseError {
+                kind: ParseErrorKind::Overflow,
+                span: 6..9
+            })
+        );
+        assert_eq!(
+            decode_as::<i64>("minus"),
+            Err(ParseError {
+                kind: ParseErrorKind::UnexpectedWord,
+                span: 0..5
+            })
+        );
+        assert_eq!(
+            decode_as::<i64>("five minus"),
+            Err(ParseError {
+                kind: ParseErrorKind::UnexpectedWord,
+                span: 5..10
+            })
+        );
+        assert_eq!(decode_as(&encode(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(decode_as(&encode(i128::MIN)), Ok(i128::MIN));
+    }
+
+    proptest! {
+        #[test]
+        fn decodes_what_encode_writes(n in prop_oneof![0..100_000_u64, any::<u64>()]) {
+            prop_assert_eq!(decode(&encode(n)), Ok(n));
+            prop_assert_eq!(decode(&encode(n).to_uppercase().replace('-', " ")), Ok(n));
+        }
+
+        #[test]
+        fn decodes_what_encode_writes_for_wide_types(n in any::<i128>(), m in any::<u128>()) {
+            prop_assert_eq!(decode_as(&encode(n)), Ok(n));
+            prop_assert_eq!(decode_as(&encode(m)), Ok(m));
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{encode_abs, ParseError, ParseErrorKind, ORDERS};
+
+/// Like [`encode`](crate::encode), but for an integer of any length written
+/// in decimal digits, e.g. "-12345678901234567890123456789012345678901234".
+/// Above the largest order the orders are stacked: "one thousand undecillion".
+pub fn encode_digits(input: &str) -> Result<String, ParseError> {
+    let (negative, digits) = match input.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    if digits.is_empty() {
+        return Err(ParseError {
+            kind: ParseErrorKind::Empty,
+            span: 0..input.len(),
+        });
+    }
+
+    let offset = input.len() - digits.len();
+    if let Some((i, c)) = digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidDigit,
+            span: offset + i..offset + i + c.len_utf8(),
+        });
+    }
+
+    let digits = digits.trim_start_matches('0');
+    let words = encode_abs_digits(digits);
+
+    Ok(match negative && !digits.is_empty() {
+        true => format!("minus {}", words),
+        false => words,
+    })
+}
+
+/// `digits` are ASCII digits without leading zeros, so empty means zero.
+pub(crate) fn encode_abs_digits(digits: &str) -> String {
+    let top = ORDERS.len() - 1;
+    let top_len = 3 * top;
+
+    // everything below the top order fits into a u128
+    if digits.len() <= top_len {
+        return encode_abs(digits.parse().unwrap_or(0));
+    }
+
+    let (upper, lower) = digits.split_at(digits.len() - top_len);
+    let upper = format!("{} {}", encode_abs_digits(upper), ORDERS[top]);
+
+    match lower.trim_start_matches('0') {
+        "" => upper,
+        lower => format!("{} {}", upper, encode_abs_digits(lower)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_digits;
+    use crate::{encode, ParseError, ParseErrorKind};
+
+    #[test]
+    fn matches_encode_for_integers() {
+        for num in [0, 7, -7, 1_000_001, i128::MIN + 1, i128::MAX] {
+            assert_eq!(encode_digits(&num.to_string()), Ok(encode(num)));
+        }
+        assert_eq!(encode_digits(&u128::MAX.to_string()), Ok(encode(u128::MAX)));
+        assert_eq!(encode_digits("+0042"), Ok("forty-two".to_string()));
+        assert_eq!(encode_digits("-000"), Ok("zero".to_string()));
+    }
+
+    #[test]
+    fn stacks_orders_above_undecillion() {
+        let thousand_undecillion = format!("1{}", "0".repeat(39));
+        assert_eq!(
+            encode_digits(&thousand_undecillion),
+            Ok("one thousand undecillion".to_string())
+        );
+
+        let huge = format!("-2{}3{}", "0".repeat(66), "0".repeat(5));
+        assert_eq!(
+            encode_digits(&huge),
+            Ok("minus two undecillion undecillion three hundred thousand".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_anything_but_digits() {
+        let error = |kind, span| Err(ParseError { kind, span });
+
+        assert_eq!(encode_digits(""), error(ParseErrorKind::Empty, 0..0));
+        assert_eq!(encode_digits("-"), error(ParseErrorKind::Empty, 0..1));
+        assert_eq!(
+            encode_digits("-1_000"),
+            error(ParseErrorKind::InvalidDigit, 2..3)
+        );
+        assert_eq!(
+            encode_digits("12\u{2009}3"),
+            error(ParseErrorKind::InvalidDigit, 2..5)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{encode, encode_ordinal};
+
+/// "three quarters", "two and five eighths". The fraction is read as given,
+/// without reducing it, and only whole numbers are split off.
+///
+/// # Panics
+///
+/// If `denominator` is zero.
+pub fn encode_fraction(numerator: u64, denominator: u64) -> String {
+    assert_ne!(denominator, 0, "denominator is zero");
+
+    match (numerator / denominator, numerator % denominator) {
+        (whole, 0) => encode(whole),
+        (0, rest) => encode_proper(rest, denominator),
+        (whole, rest) => format!("{} and {}", encode(whole), encode_proper(rest, denominator)),
+    }
+}
+
+fn encode_proper(numerator: u64, denominator: u64) -> String {
+    let plural = numerator != 1;
+    let name = match (denominator, plural) {
+        (2, false) => "half".to_string(),
+        (2, true) => "halves".to_string(),
+        (4, false) => "quarter".to_string(),
+        (4, true) => "quarters".to_string(),
+        _ => {
+            let ordinal = encode_ordinal(denominator);
+            // "hundredth" and "millionth", but "one hundred first"
+            let ordinal = match ordinal.strip_prefix("one ") {
+                Some(order) if !order.contains(' ') => order.to_string(),
+                _ => ordinal,
+            };
+
+            match plural {
+                true => format!("{}s", ordinal),
+                false => ordinal,
+            }
+        }
+    };
+
+    format!("{} {}", encode(numerator), name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_fraction;
+
+    #[test]
+    fn names_the_parts() {
+        let cases = [
+            (1, 2, "one half"),
+            (3, 2, "one and one half"),
+            (3, 4, "three quarters"),
+            (21, 8, "two and five eighths"),
+            (1, 3, "one third"),
+            (2, 3, "two thirds"),
+            (6, 8, "six eighths"),
+            (8, 4, "two"),
+            (0, 5, "zero"),
+            (7, 100, "seven hundredths"),
+            (1, 1000, "one thousandth"),
+            (5, 21, "five twenty-firsts"),
+            (3, 101, "three one hundred firsts"),
+        ];
+
+        for (numerator, denominator, words) in cases {
+            assert_eq!(encode_fraction(numerator, denominator), words);
+        }
+    }
+}
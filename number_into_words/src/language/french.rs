@@ -0,0 +1,152 @@
+use super::{groups, Gender, Language};
+
+pub struct French;
+
+const ONES: [&str; 20] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit", "dix-neuf",
+];
+// 70 to 99 are counted on from "soixante" and "quatre-vingt"
+const TENS: [&str; 7] = [
+    "zéro",
+    "dix",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+// on the long scale
+const ORDERS: [&str; 13] = [
+    "",
+    "mille",
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "quadrillion",
+    "quadrilliard",
+    "quintillion",
+    "quintilliard",
+    "sextillion", // enough for u128::MAX
+];
+
+impl Language for French {
+    fn minus(&self) -> &str {
+        "moins"
+    }
+
+    fn encode_abs(&self, num: u128, gender: Gender) -> String {
+        if num == 0 {
+            return ONES[0].to_string();
+        }
+
+        let mut words = Vec::new();
+        for (order, group) in groups(num).into_iter().enumerate().rev() {
+            match (order, group) {
+                (_, 0) => {}
+                (0, _) => {
+                    let one = match gender {
+                        Gender::Feminine => "une",
+                        _ => ONES[1],
+                    };
+                    words.push(encode_group(group, true, one));
+                }
+                // "mille" never takes "un" or a plural, and neither do the
+                // "cent" and "vingt" before it
+                (1, 1) => words.push(ORDERS[1].to_string()),
+                (1, _) => {
+                    words.push(encode_group(group, false, ONES[1]));
+                    words.push(ORDERS[1].to_string());
+                }
+                (_, _) => {
+                    words.push(encode_group(group, true, ONES[1]));
+                    let plural = if group > 1 { "s" } else { "" };
+                    words.push(format!("{}{}", ORDERS[order], plural));
+                }
+            }
+        }
+
+        words.join(" ")
+    }
+}
+
+/// "cent" and "quatre-vingt" take an "s" when they are multiplied and
+/// `plural_end` says nothing follows in the same number.
+fn encode_group(group: u16, plural_end: bool, one: &str) -> String {
+    let (hundreds, rest) = (group / 100, group % 100);
+    let mut words = Vec::new();
+
+    match hundreds {
+        0 => {}
+        1 => words.push("cent".to_string()),
+        _ => {
+            let cent = if rest == 0 && plural_end {
+                "cents"
+            } else {
+                "cent"
+            };
+            words.push(format!("{} {}", ONES[hundreds as usize], cent));
+        }
+    }
+
+    let ones = |n: u16| match n {
+        1 => one,
+        n => ONES[n as usize],
+    };
+    let below_hundred = match rest {
+        0 => None,
+        1..=19 => Some(ones(rest).to_string()),
+        20..=69 => Some(match rest % 10 {
+            0 => TENS[(rest / 10) as usize].to_string(),
+            1 => format!("{} et {}", TENS[(rest / 10) as usize], one),
+            units => format!("{}-{}", TENS[(rest / 10) as usize], ones(units)),
+        }),
+        71 => Some(format!("{} et {}", TENS[6], ONES[11])),
+        70..=79 => Some(format!("{}-{}", TENS[6], ones(rest - 60))),
+        80 if plural_end => Some("quatre-vingts".to_string()),
+        80 => Some("quatre-vingt".to_string()),
+        _ => Some(format!("quatre-vingt-{}", ones(rest - 80))),
+    };
+    words.extend(below_hundred);
+
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{encode_in, encode_in_gender, French, Gender};
+
+    #[test]
+    fn counts_in_twenties_from_seventy() {
+        let cases = [
+            (0, "zéro"),
+            (17, "dix-sept"),
+            (21, "vingt et un"),
+            (70, "soixante-dix"),
+            (71, "soixante et onze"),
+            (80, "quatre-vingts"),
+            (81, "quatre-vingt-un"),
+            (90, "quatre-vingt-dix"),
+            (99, "quatre-vingt-dix-neuf"),
+            (200, "deux cents"),
+            (201, "deux cent un"),
+            (1000, "mille"),
+            (80_000, "quatre-vingt mille"),
+            (221_000, "deux cent vingt et un mille"),
+            (1_000_000, "un million"),
+            (80_000_000, "quatre-vingts millions"),
+            (-5, "moins cinq"),
+        ];
+
+        for (num, words) in cases {
+            assert_eq!(encode_in(num, &French), words);
+        }
+        assert_eq!(
+            encode_in_gender(21, Gender::Feminine, &French),
+            "vingt et une"
+        );
+    }
+}
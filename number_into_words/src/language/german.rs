@@ -0,0 +1,147 @@
+use super::{groups, Gender, Language};
+
+/// Writes the counting form, so [`Gender`] is ignored.
+pub struct German;
+
+const ONES: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+const TENS: [&str; 10] = [
+    "null", "zehn", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig",
+    "neunzig",
+];
+// singular and plural, on the long scale
+const ORDERS: [[&str; 2]; 13] = [
+    ["", ""],
+    ["tausend", "tausend"],
+    ["Million", "Millionen"],
+    ["Milliarde", "Milliarden"],
+    ["Billion", "Billionen"],
+    ["Billiarde", "Billiarden"],
+    ["Trillion", "Trillionen"],
+    ["Trilliarde", "Trilliarden"],
+    ["Quadrillion", "Quadrillionen"],
+    ["Quadrilliarde", "Quadrilliarden"],
+    ["Quintillion", "Quintillionen"],
+    ["Quintilliarde", "Quintilliarden"],
+    ["Sextillion", "Sextillionen"], // enough for u128::MAX
+];
+
+impl Language for German {
+    fn minus(&self) -> &str {
+        "minus"
+    }
+
+    fn encode_abs(&self, num: u128, _: Gender) -> String {
+        if num == 0 {
+            return ONES[0].to_string();
+        }
+
+        let groups = groups(num);
+        let mut words = Vec::new();
+
+        // from a million up the orders are nouns of their own
+        for (order, &group) in groups.iter().enumerate().skip(2).rev() {
+            match group {
+                0 => {}
+                1 => words.push(format!("eine {}", ORDERS[order][0])),
+                _ => words.push(format!("{} {}", compound(group, false), ORDERS[order][1])),
+            }
+        }
+
+        // everything below is one word
+        let thousands = groups.get(1).copied().unwrap_or(0);
+        let mut below = String::new();
+        if thousands > 0 {
+            below.push_str(&compound(thousands, false));
+            below.push_str(ORDERS[1][0]);
+        }
+        if groups[0] > 0 {
+            below.push_str(&compound(groups[0], true));
+        }
+        if !below.is_empty() {
+            words.push(below);
+        }
+
+        words.join(" ")
+    }
+}
+
+/// One word for `group`, with the units before the tens: "einundzwanzig".
+/// A one at the very end of the number is "eins", elsewhere "ein".
+fn compound(group: u16, last: bool) -> String {
+    let ones = |n: u16| match n {
+        1 => "ein",
+        n => ONES[n as usize],
+    };
+
+    let (hundreds, rest) = (group / 100, group % 100);
+    let mut word = String::new();
+    if hundreds > 0 {
+        word.push_str(ones(hundreds));
+        word.push_str("hundert");
+    }
+
+    match (rest, rest % 10) {
+        (0, _) => {}
+        (1, _) if last => word.push_str(ONES[1]),
+        (1..=19, _) => word.push_str(ones(rest)),
+        (_, 0) => word.push_str(TENS[(rest / 10) as usize]),
+        (_, units) => {
+            word.push_str(ones(units));
+            word.push_str("und");
+            word.push_str(TENS[(rest / 10) as usize]);
+        }
+    }
+
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{encode_in, German};
+
+    #[test]
+    fn compounds_with_units_before_tens() {
+        let cases = [
+            (0, "null"),
+            (1, "eins"),
+            (16, "sechzehn"),
+            (21, "einundzwanzig"),
+            (101, "einhunderteins"),
+            (1001, "eintausendeins"),
+            (
+                123_456,
+                "einhundertdreiundzwanzigtausendvierhundertsechsundfünfzig",
+            ),
+            (1_000_000, "eine Million"),
+            (2_500_000, "zwei Millionen fünfhunderttausend"),
+            (31_000_000, "einunddreißig Millionen"),
+            (1_000_000_001, "eine Milliarde eins"),
+            (-7, "minus sieben"),
+        ];
+
+        for (num, words) in cases {
+            assert_eq!(encode_in(num, &German), words);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+mod french;
+mod german;
+mod russian;
+
+pub use french::French;
+pub use german::German;
+pub use russian::Russian;
+
+use crate::{encode_abs, Integer};
+
+/// Grammatical gender of the counted noun. `English` has none, and `German`
+/// always writes the counting form ("eins", "einundzwanzig") rather than
+/// the "ein"/"eine" used in front of a noun, so both ignore it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Gender {
+    #[default]
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+/// Spells numbers in one language, see [`encode_in`].
+pub trait Language {
+    /// Word in front of negative numbers.
+    fn minus(&self) -> &str;
+
+    /// Words for a number that is not negative, agreeing with a noun of
+    /// `gender`.
+    fn encode_abs(&self, num: u128, gender: Gender) -> String;
+}
+
+pub struct English;
+
+impl Language for English {
+    fn minus(&self) -> &str {
+        "minus"
+    }
+
+    fn encode_abs(&self, num: u128, _: Gender) -> String {
+        encode_abs(num)
+    }
+}
+
+/// `encode_in(21, &Russian)` is "двадцать один".
+pub fn encode_in<N, L>(num: N, language: &L) -> String
+where
+    N: Integer,
+    L: Language + ?Sized,
+{
+    encode_in_gender(num, Gender::default(), language)
+}
+
+/// `encode_in_gender(21, Gender::Feminine, &Russian)` is "двадцать одна".
+pub fn encode_in_gender<N, L>(num: N, gender: Gender, language: &L) -> String
+where
+    N: Integer,
+    L: Language + ?Sized,
+{
+    match num.split_sign() {
+        (true, abs) => format!("{} {}", language.minus(), language.encode_abs(abs, gender)),
+        (false, abs) => language.encode_abs(abs, gender),
+    }
+}
+
+/// Groups of three digits, lowest first.
+fn groups(mut num: u128) -> Vec<u16> {
+    let mut groups = Vec::new();
+    while num > 0 {
+        groups.push((num % 1000) as u16);
+        num /= 1000;
+    }
+    groups
+}
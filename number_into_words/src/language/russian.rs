@@ -0,0 +1,173 @@
+use super::{groups, Gender, Language};
+
+pub struct Russian;
+
+const ONES: [&str; 20] = [
+    "ноль",
+    "один",
+    "два",
+    "три",
+    "четыре",
+    "пять",
+    "шесть",
+    "семь",
+    "восемь",
+    "девять",
+    "десять",
+    "одиннадцать",
+    "двенадцать",
+    "тринадцать",
+    "четырнадцать",
+    "пятнадцать",
+    "шестнадцать",
+    "семнадцать",
+    "восемнадцать",
+    "девятнадцать",
+];
+const TENS: [&str; 10] = [
+    "ноль",
+    "десять",
+    "двадцать",
+    "тридцать",
+    "сорок",
+    "пятьдесят",
+    "шестьдесят",
+    "семьдесят",
+    "восемьдесят",
+    "девяносто",
+];
+const HUNDREDS: [&str; 10] = [
+    "ноль",
+    "сто",
+    "двести",
+    "триста",
+    "четыреста",
+    "пятьсот",
+    "шестьсот",
+    "семьсот",
+    "восемьсот",
+    "девятьсот",
+];
+// forms for 1, 2..=4 and 5..=20 of them
+const ORDERS: [[&str; 3]; 13] = [
+    ["", "", ""],
+    ["тысяча", "тысячи", "тысяч"],
+    ["миллион", "миллиона", "миллионов"],
+    ["миллиард", "миллиарда", "миллиардов"],
+    ["триллион", "триллиона", "триллионов"],
+    ["квадриллион", "квадриллиона", "квадриллионов"],
+    ["квинтиллион", "квинтиллиона", "квинтиллионов"],
+    ["секстиллион", "секстиллиона", "секстиллионов"],
+    ["септиллион", "септиллиона", "септиллионов"],
+    ["октиллион", "октиллиона", "октиллионов"],
+    ["нониллион", "нониллиона", "нониллионов"],
+    ["дециллион", "дециллиона", "дециллионов"],
+    ["ундециллион", "ундециллиона", "ундециллионов"], // enough for u128::MAX
+];
+
+impl Language for Russian {
+    fn minus(&self) -> &str {
+        "минус"
+    }
+
+    fn encode_abs(&self, num: u128, gender: Gender) -> String {
+        if num == 0 {
+            return ONES[0].to_string();
+        }
+
+        let mut words = Vec::new();
+        for (order, group) in groups(num).into_iter().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+
+            // "тысяча" is feminine, the larger orders are masculine
+            let gender = match order {
+                0 => gender,
+                1 => Gender::Feminine,
+                _ => Gender::Masculine,
+            };
+            encode_group(group, gender, &mut words);
+
+            if order > 0 {
+                words.push(ORDERS[order][plural_form(group)]);
+            }
+        }
+
+        words.join(" ")
+    }
+}
+
+fn encode_group(group: u16, gender: Gender, words: &mut Vec<&'static str>) {
+    let (hundreds, rest) = (group / 100, group % 100);
+    if hundreds > 0 {
+        words.push(HUNDREDS[hundreds as usize]);
+    }
+
+    let units = match rest {
+        0 => return,
+        1..=19 => rest,
+        _ => {
+            words.push(TENS[(rest / 10) as usize]);
+            rest % 10
+        }
+    };
+
+    let unit = match (units, gender) {
+        (0, _) => return,
+        (1, Gender::Feminine) => "одна",
+        (1, Gender::Neuter) => "одно",
+        (2, Gender::Feminine) => "две",
+        (units, _) => ONES[units as usize],
+    };
+    words.push(unit);
+}
+
+/// Which of the `ORDERS` forms goes with `count`.
+fn plural_form(count: u16) -> usize {
+    match (count % 10, count % 100) {
+        (_, 11..=14) => 2,
+        (1, _) => 0,
+        (2..=4, _) => 1,
+        _ => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{encode_in, encode_in_gender, Gender, Russian};
+
+    #[test]
+    fn plural_forms_of_orders() {
+        let cases = [
+            (0_i64, "ноль"),
+            (21, "двадцать один"),
+            (1000, "одна тысяча"),
+            (2000, "две тысячи"),
+            (5000, "пять тысяч"),
+            (11_000, "одиннадцать тысяч"),
+            (21_000, "двадцать одна тысяча"),
+            (1_222_005, "один миллион двести двадцать две тысячи пять"),
+            (3_000_000_000, "три миллиарда"),
+            (-114, "минус сто четырнадцать"),
+        ];
+
+        for (num, words) in cases {
+            assert_eq!(encode_in(num, &Russian), words);
+        }
+    }
+
+    #[test]
+    fn agrees_with_the_gender() {
+        assert_eq!(encode_in_gender(1, Gender::Feminine, &Russian), "одна");
+        assert_eq!(encode_in_gender(1, Gender::Neuter, &Russian), "одно");
+        assert_eq!(
+            encode_in_gender(2002, Gender::Feminine, &Russian),
+            "две тысячи две"
+        );
+        assert_eq!(
+            encode_in_gender(2_000_002, Gender::Neuter, &Russian),
+            "два миллиона два"
+        );
+    }
+}
@@ -1,5 +1,21 @@
 use std::iter::successors;
 
+mod decimal;
+mod decode;
+mod digits;
+mod fraction;
+mod language;
+mod ordinal;
+
+pub use decimal::{encode_cheque, encode_decimal, Decimal};
+pub use decode::{decode, decode_as, ParseError, ParseErrorKind};
+pub use digits::encode_digits;
+pub use fraction::encode_fraction;
+pub use language::{
+    encode_in, encode_in_gender, English, French, Gender, German, Language, Russian,
+};
+pub use ordinal::{encode_ordinal, encode_ordinal_numeric};
+
 const ONES: [&str; 20] = [
     "zero",
     "one",
@@ -25,29 +41,89 @@ const ONES: [&str; 20] = [
 const TENS: [&str; 10] = [
     "zero", "ten", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
 ];
-const ORDERS: [&str; 7] = [
+const ORDERS: [&str; 13] = [
     "zero",
     "thousand",
     "million",
     "billion",
     "trillion",
     "quadrillion",
-    "quintillion", // enough for u64::MAX
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion", // enough for u128::MAX
 ];
 
-pub fn encode(num: u64) -> String {
+/// Integer types [`encode`] and [`decode_as`] accept.
+pub trait Integer: Copy {
+    /// Whether the number is negative, and its absolute value.
+    fn split_sign(self) -> (bool, u128);
+
+    /// The number with this sign and absolute value, if it fits.
+    fn from_sign(negative: bool, abs: u128) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {
+        $(impl Integer for $ty {
+            fn split_sign(self) -> (bool, u128) {
+                (false, self as u128)
+            }
+
+            fn from_sign(negative: bool, abs: u128) -> Option<Self> {
+                match negative && abs != 0 {
+                    true => None,
+                    false => <$ty>::try_from(abs).ok(),
+                }
+            }
+        })*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($ty:ty => $unsigned:ty),*) => {
+        $(impl Integer for $ty {
+            fn split_sign(self) -> (bool, u128) {
+                (self < 0, self.unsigned_abs() as u128)
+            }
+
+            fn from_sign(negative: bool, abs: u128) -> Option<Self> {
+                match negative {
+                    true => <$unsigned>::try_from(abs)
+                        .ok()
+                        .and_then(|abs| (0 as $ty).checked_sub_unsigned(abs)),
+                    false => <$ty>::try_from(abs).ok(),
+                }
+            }
+        })*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+/// "twenty-one", "minus one hundred five". See [`encode_in`] for other
+/// languages.
+pub fn encode<N: Integer>(num: N) -> String {
+    encode_in(num, &English)
+}
+
+fn encode_abs(num: u128) -> String {
     match num {
         0..=19 => ONES[num as usize].to_string(),
         20..=99 => {
             let upper = (num / 10) as usize;
             match num % 10 {
                 0 => TENS[upper].to_string(),
-                lower => format!("{}-{}", TENS[upper], encode(lower)),
+                lower => format!("{}-{}", TENS[upper], encode_abs(lower)),
             }
         }
         100..=999 => format_num(num, 100, "hundred"),
         _ => {
-            let (div, order) = successors(Some(1u64), |v| v.checked_mul(1000))
+            let (div, order) = successors(Some(1u128), |v| v.checked_mul(1000))
                 .zip(ORDERS.iter())
                 .find(|&(e, _)| e > num / 1000)
                 .unwrap();
@@ -57,11 +133,11 @@ pub fn encode(num: u64) -> String {
     }
 }
 
-fn format_num(num: u64, div: u64, order: &str) -> String {
+fn format_num(num: u128, div: u128, order: &str) -> String {
     match (num / div, num % div) {
-        (upper, 0) => format!("{} {}", encode(upper), order),
+        (upper, 0) => format!("{} {}", encode_abs(upper), order),
         (upper, lower) => {
-            format!("{} {} {}", encode(upper), order, encode(lower))
+            format!("{} {} {}", encode_abs(upper), order, encode_abs(lower))
         }
     }
 }
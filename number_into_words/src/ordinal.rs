@@ -0,0 +1,92 @@
+use crate::encode;
+
+const IRREGULAR: [(&str, &str); 7] = [
+    ("one", "first"),
+    ("two", "second"),
+    ("three", "third"),
+    ("five", "fifth"),
+    ("eight", "eighth"),
+    ("nine", "ninth"),
+    ("twelve", "twelfth"),
+];
+
+/// "first", "twenty-first", "one hundred third", "one millionth".
+pub fn encode_ordinal(num: u64) -> String {
+    let cardinal = encode(num);
+
+    // only the last word changes, it may follow a space or a hyphen
+    let start = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = cardinal.split_at(start);
+
+    let last = match IRREGULAR.iter().find(|(cardinal, _)| *cardinal == last) {
+        Some((_, ordinal)) => ordinal.to_string(),
+        None => match last.strip_suffix('y') {
+            Some(stem) => format!("{}ieth", stem),
+            None => format!("{}th", last),
+        },
+    };
+
+    format!("{}{}", head, last)
+}
+
+/// "1st", "2nd", "11th", "21st", "112th".
+pub fn encode_ordinal_numeric(num: u64) -> String {
+    let suffix = match (num % 10, num % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{}{}", num, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_ordinal, encode_ordinal_numeric};
+
+    #[test]
+    fn words() {
+        let cases = [
+            (0, "zeroth"),
+            (1, "first"),
+            (5, "fifth"),
+            (8, "eighth"),
+            (12, "twelfth"),
+            (14, "fourteenth"),
+            (20, "twentieth"),
+            (21, "twenty-first"),
+            (99, "ninety-ninth"),
+            (103, "one hundred third"),
+            (1_000_000, "one millionth"),
+            (2_000_042, "two million forty-second"),
+        ];
+
+        for (num, ordinal) in cases {
+            assert_eq!(encode_ordinal(num), ordinal);
+        }
+    }
+
+    #[test]
+    fn numeric() {
+        let cases = [
+            (0, "0th"),
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (21, "21st"),
+            (111, "111th"),
+            (112, "112th"),
+            (122, "122nd"),
+            (1003, "1003rd"),
+        ];
+
+        for (num, ordinal) in cases {
+            assert_eq!(encode_ordinal_numeric(num), ordinal);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use signal::{Runtime, Signal};
+
+const SIGNALS: usize = 10_000;
+
+fn signals_with_effects(cx: Runtime) -> Vec<Signal<usize>> {
+    (0..SIGNALS)
+        .map(|i| {
+            let signal = cx.create_signal(i);
+            cx.create_effect(move || {
+                std::hint::black_box(signal.get());
+            });
+            signal
+        })
+        .collect()
+}
+
+fn create_and_dispose(c: &mut Criterion) {
+    // the same runtime for every iteration, so disposed slots pile up or get
+    // reused
+    let cx = Runtime::new();
+
+    c.bench_function("create and dispose 10k signals with effects", |b| {
+        b.iter(|| {
+            let scope = cx.create_root(|scope| {
+                signals_with_effects(cx);
+                scope
+            });
+            scope.dispose();
+        })
+    });
+
+    cx.dispose();
+}
+
+fn update(c: &mut Criterion) {
+    let cx = Runtime::new();
+    let signals = signals_with_effects(cx);
+
+    c.bench_function("update 10k signals with effects", |b| {
+        b.iter(|| {
+            cx.batch(|| {
+                for signal in &signals {
+                    signal.update(|value| *value += 1);
+                }
+            })
+        })
+    });
+
+    cx.dispose();
+}
+
+fn fan_in(c: &mut Criterion) {
+    let cx = Runtime::new();
+    let signals = (0..SIGNALS).map(|i| cx.create_signal(i)).collect::<Vec<_>>();
+    let sum = cx.create_memo({
+        let signals = signals.clone();
+        move || signals.iter().map(Signal::get).sum::<usize>()
+    });
+    cx.create_effect(move || {
+        std::hint::black_box(sum.get());
+    });
+
+    c.bench_function("memo over 10k signals", |b| {
+        b.iter(|| signals[0].update(|value| *value += 1))
+    });
+
+    cx.dispose();
+}
+
+criterion_group!(benches, create_and_dispose, update, fan_in);
+criterion_main!(benches);
@@ -0,0 +1,60 @@
+use std::time::{Duration, Instant};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use signal::Runtime;
+
+const ROWS: usize = 1000;
+
+fn setup(selector: bool) -> (Runtime, signal::Signal<usize>) {
+    let cx = Runtime::new();
+    let selected = cx.create_signal(0);
+
+    if selector {
+        let is_selected = cx.create_selector(selected);
+        for row in 0..ROWS {
+            cx.create_effect(move || {
+                std::hint::black_box(is_selected.is_selected(&row));
+            });
+        }
+    } else {
+        for row in 0..ROWS {
+            cx.create_effect(move || {
+                std::hint::black_box(selected.get() == row);
+            });
+        }
+    }
+
+    (cx, selected)
+}
+
+fn change_selection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("change selection of 1000 rows");
+
+    for (name, selector) in [("naive", false), ("selector", true)] {
+        group.bench_function(name, |b| {
+            // setup and disposal are not measured
+            b.iter_custom(|iters| {
+                let mut elapsed = Duration::ZERO;
+
+                for _ in 0..iters {
+                    let (cx, selected) = setup(selector);
+
+                    let start = Instant::now();
+                    for row in 1..10 {
+                        selected.set(row);
+                    }
+                    elapsed += start.elapsed();
+
+                    cx.dispose();
+                }
+
+                elapsed
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, change_selection);
+criterion_main!(benches);
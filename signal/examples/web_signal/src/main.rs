@@ -24,13 +24,13 @@ fn main() {
     body.append_child(&input).unwrap();
     body.append_child(&dec).unwrap();
 
-    let cx: &'static Runtime = Box::leak(Box::default());
+    let cx = Runtime::new();
     let count = cx.create_signal(0);
-    let double_counts = move || count.get() as f64 * 2.3;
+    let double_counts = cx.create_memo(move || count.get() as f64 * 2.3);
 
     cx.create_effect(move || p.set_text_content(Some(&count.get().to_string())));
 
-    cx.create_effect(move || double.set_text_content(Some(&double_counts().to_string())));
+    cx.create_effect(move || double.set_text_content(Some(&double_counts.get().to_string())));
 
     add_event_listener(&input, "input", move |_: KeyboardEvent| {
         log!("+1");
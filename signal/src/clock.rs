@@ -0,0 +1,137 @@
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+pub type Timer = Box<dyn FnOnce()>;
+
+/// Time source and timer queue for the time-based signals, e.g.
+/// [`Runtime::debounced`](crate::Runtime::debounced).
+pub trait Clock {
+    /// Time since an arbitrary fixed point.
+    fn now(&self) -> Duration;
+
+    /// Calls `timer` once `now()` has reached `at`.
+    fn schedule(&self, at: Duration, timer: Timer);
+}
+
+#[derive(Default)]
+struct Timers {
+    // keyed by deadline, then by insertion order
+    queue: RefCell<BTreeMap<(Duration, u64), Timer>>,
+    next_id: Cell<u64>,
+}
+
+impl Timers {
+    fn schedule(&self, at: Duration, timer: Timer) {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.queue.borrow_mut().insert((at, id), timer);
+    }
+
+    fn next_deadline(&self) -> Option<Duration> {
+        self.queue.borrow().keys().next().map(|(at, _)| *at)
+    }
+
+    /// Calls every timer that is due at `now`, including the ones they
+    /// schedule, and returns how many ran.
+    fn run_due(&self, now: Duration) -> usize {
+        let mut count = 0;
+
+        loop {
+            let timer = {
+                let mut queue = self.queue.borrow_mut();
+                match queue.first_entry() {
+                    Some(entry) if entry.key().0 <= now => entry.remove(),
+                    _ => return count,
+                }
+            };
+
+            timer();
+            count += 1;
+        }
+    }
+}
+
+/// Clock that only moves when told to, so timing can be tested
+/// deterministically.
+#[derive(Clone, Default)]
+pub struct VirtualClock {
+    now: Rc<Cell<Duration>>,
+    timers: Rc<Timers>,
+}
+
+impl VirtualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the clock forward, firing every timer on the way at its own
+    /// deadline.
+    pub fn advance(&self, by: Duration) {
+        let end = self.now.get() + by;
+
+        while let Some(at) = self.timers.next_deadline().filter(|at| *at <= end) {
+            self.now.set(self.now.get().max(at));
+            self.timers.run_due(at);
+        }
+
+        self.now.set(end);
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+
+    fn schedule(&self, at: Duration, timer: Timer) {
+        self.timers.schedule(at, timer);
+    }
+}
+
+/// Clock on the system's monotonic time. Timers fire when the message loop
+/// calls [`SystemClock::run_due`], e.g. after waiting at most
+/// [`SystemClock::time_until_next`] for new messages.
+#[derive(Clone)]
+pub struct SystemClock {
+    start: Instant,
+    timers: Rc<Timers>,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        SystemClock {
+            start: Instant::now(),
+            timers: Default::default(),
+        }
+    }
+
+    pub fn run_due(&self) -> usize {
+        self.timers.run_due(self.now())
+    }
+
+    /// How long the message loop can sleep before a timer is due, or `None`
+    /// if no timer is scheduled.
+    pub fn time_until_next(&self) -> Option<Duration> {
+        let now = self.now();
+
+        self.timers.next_deadline().map(|at| at.saturating_sub(now))
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    fn schedule(&self, at: Duration, timer: Timer) {
+        self.timers.schedule(at, timer);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Runtime, Scope};
+
+impl Runtime {
+    /// Makes `value` available to [`Runtime::use_context`] in the current
+    /// scope and every scope below it, replacing a value of the same type
+    /// provided in this scope before.
+    pub fn provide_context<T: 'static>(&self, value: T) {
+        let state = self.state();
+        state.provide_context(state.owner(), value);
+    }
+
+    /// Returns the closest value of type `T` provided in the current scope or
+    /// one of its parents.
+    pub fn use_context<T: Clone + 'static>(&self) -> Option<T> {
+        let state = self.state();
+        state.use_context(state.owner())
+    }
+}
+
+impl Scope {
+    pub fn provide_context<T: 'static>(&self, value: T) {
+        self.runtime().state().provide_context(self.id(), value);
+    }
+
+    pub fn use_context<T: Clone + 'static>(&self) -> Option<T> {
+        self.runtime().state().use_context(self.id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::Runtime;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Theme(&'static str);
+
+    #[test]
+    fn context_is_found_in_parent_scopes_and_effects() {
+        let cx = Runtime::new();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        cx.create_root(|root| {
+            root.provide_context(Theme("dark"));
+
+            cx.create_scope(|_| {
+                cx.create_effect({
+                    let seen = seen.clone();
+                    move || seen.borrow_mut().push(cx.use_context::<Theme>())
+                });
+            });
+        });
+
+        assert_eq!(*seen.borrow(), [Some(Theme("dark"))]);
+        assert_eq!(cx.use_context::<Theme>(), None);
+        assert_eq!(cx.use_context::<u32>(), None);
+    }
+
+    #[test]
+    fn nested_scope_shadows_parent_context() {
+        let cx = Runtime::new();
+
+        cx.create_root(|_| {
+            cx.provide_context(Theme("dark"));
+
+            let inner = cx.create_scope(|inner| {
+                cx.provide_context(Theme("light"));
+                assert_eq!(cx.use_context(), Some(Theme("light")));
+                inner
+            });
+
+            assert_eq!(cx.use_context(), Some(Theme("dark")));
+            assert_eq!(inner.use_context(), Some(Theme("light")));
+
+            // a sibling only sees the parent value
+            cx.create_scope(|sibling| {
+                assert_eq!(sibling.use_context(), Some(Theme("dark")));
+            });
+        });
+    }
+
+    #[test]
+    fn context_is_dropped_with_its_scope() {
+        let cx = Runtime::new();
+        let store = Rc::new(());
+
+        let (root, child) = cx.create_root(|root| {
+            cx.provide_context(store.clone());
+            (root, cx.create_scope(|child| child))
+        });
+        assert_eq!(child.use_context::<Rc<()>>().map(|_| ()), Some(()));
+        assert_eq!(Rc::strong_count(&store), 2);
+
+        root.dispose();
+        assert_eq!(Rc::strong_count(&store), 1);
+        assert_eq!(child.use_context::<Rc<()>>(), None);
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt::{self, Write};
+use std::rc::Rc;
+
+use crate::Runtime;
+
+/// Snapshot of the reactive graph returned by [`Runtime::graph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    pub signals: Vec<SignalInfo>,
+    pub effects: Vec<EffectInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalInfo {
+    pub id: usize,
+    pub name: Option<String>,
+    /// Ids of the effects that read the signal on their last run.
+    pub subscribers: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectInfo {
+    pub id: usize,
+    pub name: Option<String>,
+    pub runs: usize,
+    /// Signal written by the effect when it computes a memo.
+    pub memo: Option<usize>,
+}
+
+/// Signal or effect as reported by a [`TraceEvent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceNode {
+    pub id: usize,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    SignalChanged {
+        signal: TraceNode,
+        subscribers: Vec<TraceNode>,
+    },
+    EffectRun {
+        effect: TraceNode,
+        /// Signals whose changes queued this run, empty for the initial run.
+        triggered_by: Vec<TraceNode>,
+    },
+}
+
+impl Runtime {
+    pub fn graph(&self) -> Graph {
+        self.state().graph()
+    }
+
+    /// Calls `tracer` for every signal change and effect run, e.g. to log why
+    /// an effect ran.
+    pub fn set_tracer(&self, tracer: impl Fn(&TraceEvent) + 'static) {
+        self.state().set_tracer(Some(Rc::new(tracer)));
+    }
+
+    pub fn clear_tracer(&self) {
+        self.state().set_tracer(None);
+    }
+}
+
+impl Graph {
+    /// Renders the graph in Graphviz DOT. Signals are ellipses, effects are
+    /// boxes and memos are drawn with a dashed edge from their effect.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph reactive {\n");
+
+        for signal in &self.signals {
+            let label = label(&signal.name, "signal", signal.id);
+            writeln!(dot, "    s{} [label={:?}];", signal.id, label).unwrap();
+        }
+
+        for effect in &self.effects {
+            let label = format!(
+                "{} ({} runs)",
+                label(&effect.name, "effect", effect.id),
+                effect.runs
+            );
+            writeln!(dot, "    e{} [shape=box, label={:?}];", effect.id, label).unwrap();
+        }
+
+        for signal in &self.signals {
+            for sub in &signal.subscribers {
+                writeln!(dot, "    s{} -> e{};", signal.id, sub).unwrap();
+            }
+        }
+
+        for effect in &self.effects {
+            if let Some(memo) = effect.memo {
+                writeln!(dot, "    e{} -> s{} [style=dashed];", effect.id, memo).unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn to_json(&self) -> String {
+        let signals = self
+            .signals
+            .iter()
+            .map(|signal| {
+                format!(
+                    r#"{{"id":{},"name":{},"subscribers":[{}]}}"#,
+                    signal.id,
+                    json_name(&signal.name),
+                    join(&signal.subscribers),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let effects = self
+            .effects
+            .iter()
+            .map(|effect| {
+                format!(
+                    r#"{{"id":{},"name":{},"runs":{},"memo":{}}}"#,
+                    effect.id,
+                    json_name(&effect.name),
+                    effect.runs,
+                    effect
+                        .memo
+                        .map_or("null".to_string(), |memo| memo.to_string()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            r#"{{"signals":[{}],"effects":[{}]}}"#,
+            signals.join(","),
+            effects.join(",")
+        )
+    }
+}
+
+impl fmt::Display for TraceNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{}#{}", name, self.id),
+            None => write!(f, "#{}", self.id),
+        }
+    }
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceEvent::SignalChanged {
+                signal,
+                subscribers,
+            } => write!(
+                f,
+                "signal {} changed, notifying [{}]",
+                signal,
+                join(subscribers)
+            ),
+            TraceEvent::EffectRun {
+                effect,
+                triggered_by,
+            } if triggered_by.is_empty() => write!(f, "effect {} ran", effect),
+            TraceEvent::EffectRun {
+                effect,
+                triggered_by,
+            } => write!(
+                f,
+                "effect {} ran because of [{}]",
+                effect,
+                join(triggered_by)
+            ),
+        }
+    }
+}
+
+fn label(name: &Option<String>, kind: &str, id: usize) -> String {
+    match name {
+        Some(name) => name.clone(),
+        None => format!("{} {}", kind, id),
+    }
+}
+
+fn join(items: &[impl fmt::Display]) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn json_name(name: &Option<String>) -> String {
+    let Some(name) = name else {
+        return "null".to_string();
+    };
+
+    let mut json = String::from("\"");
+    for c in name.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::Runtime;
+
+    #[test]
+    fn graph_lists_names_edges_and_runs() {
+        let cx = Runtime::new();
+        let count = cx.create_signal(1).named("count");
+        let double = cx.create_memo(move || count.get() * 2).named("double");
+        let log = cx.create_effect(move || {
+            double.get();
+        });
+        log.named("log");
+
+        count.set(2);
+        count.set(3);
+        assert_eq!(log.runs(), 3);
+
+        let graph = cx.graph();
+        assert_eq!(
+            graph.to_json(),
+            concat!(
+                r#"{"signals":[{"id":0,"name":"count","subscribers":[0]},"#,
+                r#"{"id":1,"name":"double","subscribers":[1]}],"#,
+                r#""effects":[{"id":0,"name":null,"runs":3,"memo":1},"#,
+                r#"{"id":1,"name":"log","runs":3,"memo":null}]}"#,
+            )
+        );
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("s0 [label=\"count\"];"));
+        assert!(dot.contains("e1 [shape=box, label=\"log (3 runs)\"];"));
+        assert!(dot.contains("s0 -> e0;"));
+        assert!(dot.contains("e0 -> s1 [style=dashed];"));
+        assert!(dot.contains("s1 -> e1;"));
+    }
+
+    #[test]
+    fn tracer_explains_why_effects_ran() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(0).named("a");
+        let b = cx.create_signal(0).named("b");
+        cx.create_effect(move || {
+            a.get();
+            b.get();
+        })
+        .named("sum");
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        cx.set_tracer({
+            let events = events.clone();
+            move |event| events.borrow_mut().push(event.to_string())
+        });
+
+        cx.batch(|| {
+            a.set(1);
+            b.set(1);
+        });
+        cx.clear_tracer();
+        a.set(2);
+
+        assert_eq!(
+            *events.borrow(),
+            [
+                "signal a#0 changed, notifying [sum#0]",
+                "signal b#1 changed, notifying [sum#0]",
+                "effect sum#0 ran because of [a#0, b#1]",
+            ]
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use crate::runtime::{EffectId, Runtime};
+
+/// Handle to an effect created with [`Runtime::create_effect`]. Dropping it
+/// does not stop the effect; it lives as long as its owner scope.
+#[derive(Clone, Copy)]
+pub struct Effect {
+    cx: Runtime,
+    id: EffectId,
+}
+
+impl Effect {
+    pub(crate) fn new(cx: Runtime, id: EffectId) -> Self {
+        Effect { cx, id }
+    }
+
+    /// Name shown for this node in [`Runtime::graph`] and trace events.
+    pub fn named(self, name: impl Into<String>) -> Self {
+        self.cx.state().set_effect_name(self.id, name.into());
+        self
+    }
+
+    /// How many times the effect has run, including the initial run.
+    pub fn runs(&self) -> usize {
+        self.cx.state().effect_runs(self.id)
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReactiveError {
+    /// An effect was triggered more than the runtime's max iterations within
+    /// a single update, usually because it writes a signal it depends on.
+    CycleDetected { iterations: usize },
+    /// The signal, or the runtime it belongs to, has been disposed.
+    Disposed,
+}
+
+impl Display for ReactiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReactiveError::CycleDetected { iterations } => write!(
+                f,
+                "effect re-triggered itself more than {} times in one update",
+                iterations
+            ),
+            ReactiveError::Disposed => write!(f, "signal has been disposed"),
+        }
+    }
+}
+
+impl std::error::Error for ReactiveError {}
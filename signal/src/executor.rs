@@ -0,0 +1,129 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+pub type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Runs the futures started by resources. Implemented for closures, so any
+/// executor can be plugged in, e.g. `|future| { tokio::task::spawn_local(future); }`.
+pub trait Executor {
+    fn spawn_local(&self, future: LocalFuture);
+}
+
+impl<F> Executor for F
+where
+    F: Fn(LocalFuture),
+{
+    fn spawn_local(&self, future: LocalFuture) {
+        self(future)
+    }
+}
+
+/// Minimal single-threaded executor that only polls when asked to, which
+/// makes it handy for tests and for driving futures from a message loop.
+#[derive(Clone, Default)]
+pub struct LocalExecutor {
+    tasks: Rc<RefCell<Tasks>>,
+    woken: Arc<Mutex<Vec<usize>>>,
+}
+
+#[derive(Default)]
+struct Tasks {
+    slots: Vec<Option<LocalFuture>>,
+    // slots of finished tasks, reused by new ones; a stale waker only causes
+    // a spurious poll of the new task
+    free: Vec<usize>,
+}
+
+struct TaskWaker {
+    id: usize,
+    woken: Arc<Mutex<Vec<usize>>>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.woken.lock().unwrap().push(self.id);
+    }
+}
+
+impl LocalExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Polls woken tasks until none of them can make progress and returns
+    /// how many tasks are still pending.
+    pub fn run_until_stalled(&self) -> usize {
+        loop {
+            let woken = std::mem::take(&mut *self.woken.lock().unwrap());
+            if woken.is_empty() {
+                break;
+            }
+
+            for id in woken {
+                // taken out of its slot so the task can spawn new ones
+                let Some(mut task) = self.tasks.borrow_mut().slots[id].take() else {
+                    continue;
+                };
+
+                let waker = Waker::from(Arc::new(TaskWaker {
+                    id,
+                    woken: self.woken.clone(),
+                }));
+
+                let poll = task.as_mut().poll(&mut Context::from_waker(&waker));
+                let mut tasks = self.tasks.borrow_mut();
+                match poll {
+                    Poll::Pending => tasks.slots[id] = Some(task),
+                    Poll::Ready(()) => tasks.free.push(id),
+                }
+            }
+        }
+
+        let tasks = self.tasks.borrow();
+        tasks.slots.len() - tasks.free.len()
+    }
+}
+
+impl Executor for LocalExecutor {
+    fn spawn_local(&self, future: LocalFuture) {
+        let id = {
+            let mut tasks = self.tasks.borrow_mut();
+            match tasks.free.pop() {
+                Some(id) => {
+                    tasks.slots[id] = Some(future);
+                    id
+                }
+                None => {
+                    tasks.slots.push(Some(future));
+                    tasks.slots.len() - 1
+                }
+            }
+        };
+
+        self.woken.lock().unwrap().push(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Executor, LocalExecutor};
+
+    #[test]
+    fn finished_tasks_free_their_slots() {
+        let executor = LocalExecutor::new();
+
+        for _ in 0..100 {
+            executor.spawn_local(Box::pin(async {}));
+            assert_eq!(executor.run_until_stalled(), 0);
+        }
+        executor.spawn_local(Box::pin(std::future::pending()));
+        executor.spawn_local(Box::pin(async {}));
+        assert_eq!(executor.run_until_stalled(), 1);
+
+        assert_eq!(executor.tasks.borrow().slots.len(), 2);
+    }
+}
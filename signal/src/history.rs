@@ -0,0 +1,326 @@
+use std::collections::VecDeque;
+
+use crate::{ReadSignal, Runtime, Signal};
+
+struct History<T> {
+    capacity: usize,
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    transaction_depth: usize,
+    // value before the first change of the open transaction
+    transaction_start: Option<T>,
+}
+
+/// Signal that keeps a snapshot of its value before every change, so changes
+/// can be undone and redone.
+///
+/// Writes that go directly to the wrapped [`Signal`] are not recorded.
+pub struct HistorySignal<T> {
+    cx: Runtime,
+    value: Signal<T>,
+    history: Signal<History<T>>,
+    can_undo: Signal<bool>,
+    can_redo: Signal<bool>,
+}
+
+impl<T> Clone for HistorySignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for HistorySignal<T> {}
+
+impl Runtime {
+    /// Records the changes made to `signal` through the returned handle,
+    /// keeping at most `capacity` undo steps.
+    pub fn with_history<T>(&self, signal: Signal<T>, capacity: usize) -> HistorySignal<T>
+    where
+        T: Clone + 'static,
+    {
+        HistorySignal {
+            cx: *self,
+            value: signal,
+            history: self.create_signal(History {
+                capacity,
+                undo: VecDeque::new(),
+                redo: Vec::new(),
+                transaction_depth: 0,
+                transaction_start: None,
+            }),
+            can_undo: self.create_signal(false),
+            can_redo: self.create_signal(false),
+        }
+    }
+}
+
+impl<T> HistorySignal<T>
+where
+    T: Clone + 'static,
+{
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    pub fn get_untracked(&self) -> T {
+        self.value.get_untracked()
+    }
+}
+
+impl<T> HistorySignal<T>
+where
+    T: 'static,
+{
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.value.with(f)
+    }
+
+    pub fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.value.with_untracked(f)
+    }
+
+    pub fn can_undo(&self) -> ReadSignal<bool> {
+        self.can_undo.read_only()
+    }
+
+    pub fn can_redo(&self) -> ReadSignal<bool> {
+        self.can_redo.read_only()
+    }
+}
+
+impl<T> HistorySignal<T>
+where
+    T: Clone + 'static,
+{
+    pub fn set(&self, value: T) {
+        self.update(|current| *current = value);
+    }
+
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let previous = self.value.get_untracked();
+
+        self.cx.batch(|| {
+            self.history.update(|history| {
+                if history.transaction_depth > 0 {
+                    history.transaction_start.get_or_insert(previous);
+                } else {
+                    history.record(previous);
+                }
+            });
+            self.value.update(f);
+            self.sync_flags();
+        });
+    }
+
+    /// Groups every change until the matching [`HistorySignal::commit`] into
+    /// one undo step, e.g. from pointer down to pointer up of a drag.
+    /// Transactions can be nested; only the outermost one records.
+    pub fn begin(&self) {
+        self.history
+            .update(|history| history.transaction_depth += 1);
+    }
+
+    /// Ends the innermost transaction. Does nothing without an open one.
+    pub fn commit(&self) {
+        self.cx.batch(|| {
+            self.history.update(|history| {
+                if history.transaction_depth == 0 {
+                    return;
+                }
+                history.transaction_depth -= 1;
+
+                if history.transaction_depth == 0 {
+                    if let Some(start) = history.transaction_start.take() {
+                        history.record(start);
+                    }
+                }
+            });
+            self.sync_flags();
+        });
+    }
+
+    /// Runs `f` as a single transaction.
+    pub fn transaction<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.begin();
+        let result = f();
+        self.commit();
+        result
+    }
+
+    /// Restores the value before the last change. The changes of an open
+    /// transaction are recorded first, so they are the step being undone,
+    /// and later changes until the matching commit form a new step.
+    pub fn undo(&self) {
+        self.step(|history, current| {
+            let previous = history.undo.pop_back()?;
+            history.redo.push(current);
+            Some(previous)
+        });
+    }
+
+    pub fn redo(&self) {
+        self.step(|history, current| {
+            let next = history.redo.pop()?;
+            history.undo.push_back(current);
+            Some(next)
+        });
+    }
+
+    fn step(&self, f: impl FnOnce(&mut History<T>, T) -> Option<T>) {
+        self.cx.batch(|| {
+            let current = self.value.get_untracked();
+
+            let mut value = None;
+            self.history.update(|history| {
+                if let Some(start) = history.transaction_start.take() {
+                    history.record(start);
+                }
+
+                value = f(history, current);
+            });
+
+            if let Some(value) = value {
+                self.value.set(value);
+            }
+            self.sync_flags();
+        });
+    }
+
+    fn sync_flags(&self) {
+        let (can_undo, can_redo) = self
+            .history
+            .with_untracked(|history| (!history.undo.is_empty(), !history.redo.is_empty()));
+
+        // only notify when the flag flips
+        if self.can_undo.get_untracked() != can_undo {
+            self.can_undo.set(can_undo);
+        }
+        if self.can_redo.get_untracked() != can_redo {
+            self.can_redo.set(can_redo);
+        }
+    }
+}
+
+impl<T> History<T> {
+    fn record(&mut self, previous: T) {
+        self.redo.clear();
+        self.undo.push_back(previous);
+
+        while self.undo.len() > self.capacity {
+            self.undo.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::Runtime;
+
+    #[test]
+    fn changes_can_be_undone_and_redone() {
+        let cx = Runtime::new();
+        let count = cx.with_history(cx.create_signal(0), 2);
+
+        count.set(1);
+        count.set(2);
+        count.update(|count| *count += 1);
+
+        count.undo();
+        count.undo();
+        assert_eq!(count.get(), 1);
+
+        // the oldest step was dropped
+        count.undo();
+        assert_eq!(count.get(), 1);
+        assert!(!count.can_undo().get());
+
+        count.redo();
+        assert_eq!(count.get(), 2);
+
+        // a new change forgets the redo steps
+        count.set(10);
+        assert!(!count.can_redo().get());
+        count.redo();
+        assert_eq!(count.get(), 10);
+    }
+
+    #[test]
+    fn transaction_is_one_step() {
+        let cx = Runtime::new();
+        let point = cx.with_history(cx.create_signal((0, 0)), 10);
+
+        point.begin();
+        for x in 1..=5 {
+            point.set((x, x * 2));
+        }
+        point.commit();
+        point.transaction(|| point.set((7, 7)));
+
+        point.undo();
+        assert_eq!(point.get(), (5, 10));
+        point.undo();
+        assert_eq!(point.get(), (0, 0));
+        assert!(!point.can_undo().get());
+    }
+
+    #[test]
+    fn undo_during_a_transaction_keeps_it_open() {
+        let cx = Runtime::new();
+        let x = cx.with_history(cx.create_signal(0), 10);
+        let runs = Rc::new(RefCell::new(0));
+        cx.create_effect({
+            let runs = runs.clone();
+            move || {
+                x.get();
+                *runs.borrow_mut() += 1;
+            }
+        });
+
+        // Ctrl+Z while dragging, then the pointer goes up
+        x.begin();
+        x.set(1);
+        x.set(2);
+        x.undo();
+        assert_eq!(x.get(), 0);
+        x.set(3);
+        x.commit();
+        x.commit();
+
+        // the runtime still flushes
+        x.set(4);
+        assert_eq!(*runs.borrow(), 6);
+
+        x.undo();
+        assert_eq!(x.get(), 3);
+        x.undo();
+        assert_eq!(x.get(), 0);
+    }
+
+    #[test]
+    fn flags_notify_when_they_flip() {
+        let cx = Runtime::new();
+        let text = cx.with_history(cx.create_signal(String::new()), 10);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        cx.create_effect({
+            let log = log.clone();
+            move || {
+                log.borrow_mut()
+                    .push((text.can_undo().get(), text.can_redo().get()))
+            }
+        });
+
+        text.set("a".to_string());
+        text.set("ab".to_string());
+        text.undo();
+        text.undo();
+
+        assert_eq!(
+            *log.borrow(),
+            [(false, false), (true, false), (true, true), (false, true)]
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use crate::error::ReactiveError;
+use crate::runtime::{Runtime, SignalId};
+
+/// Read-only derived value that is recomputed when its dependencies change
+/// and only notifies its own subscribers when the new value differs.
+pub struct Memo<T> {
+    cx: Runtime,
+    id: SignalId,
+    ty: PhantomData<T>,
+}
+
+impl<T> Clone for Memo<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Memo<T> {}
+
+impl<T> Memo<T> {
+    pub(crate) fn new(cx: Runtime, id: SignalId) -> Self {
+        Memo {
+            cx,
+            id,
+            ty: PhantomData,
+        }
+    }
+
+    /// Name shown for this node in [`Runtime::graph`] and trace events.
+    pub fn named(self, name: impl Into<String>) -> Self {
+        self.cx.state().set_signal_name(self.id, name.into());
+        self
+    }
+}
+
+impl<T> Memo<T>
+where
+    T: Clone + 'static,
+{
+    pub fn get(&self) -> T {
+        self.with(T::clone)
+    }
+
+    pub fn get_untracked(&self) -> T {
+        self.with_untracked(T::clone)
+    }
+
+    pub fn try_get(&self) -> Result<T, ReactiveError> {
+        self.try_with(T::clone)
+    }
+}
+
+impl<T> Memo<T>
+where
+    T: 'static,
+{
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let state = self.cx.state();
+        state.refresh_memo(self.id);
+        state.track(self.id);
+
+        state.with_value(self.id, |value: &Option<T>| f(value.as_ref().unwrap()))
+    }
+
+    pub fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let state = self.cx.state();
+        state.refresh_memo(self.id);
+
+        state.with_value(self.id, |value: &Option<T>| f(value.as_ref().unwrap()))
+    }
+
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, ReactiveError> {
+        let state = self.cx.try_state()?;
+        state.refresh_memo(self.id);
+        state.track(self.id);
+
+        state.try_with_value(self.id, |value: &Option<T>| f(value.as_ref().unwrap()))
+    }
+}
@@ -0,0 +1,224 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::runtime::SignalId;
+use crate::{Runtime, Signal};
+
+type Save = Rc<dyn Fn() -> serde_json::Result<Value>>;
+type Restore = Rc<dyn Fn(Value) -> serde_json::Result<()>>;
+
+struct Persisted {
+    save: Save,
+    restore: Restore,
+}
+
+#[derive(Default)]
+pub(crate) struct Registry {
+    signals: BTreeMap<String, Persisted>,
+    keys: HashMap<SignalId, String>,
+    // restored values whose signal has not been created yet, and last values
+    // of disposed signals
+    unclaimed: BTreeMap<String, Value>,
+}
+
+impl Registry {
+    /// Called when a signal is disposed. Its last value is kept as unclaimed,
+    /// so it is still saved and goes to the next signal with the same key,
+    /// e.g. when a closed window is opened again.
+    pub(crate) fn forget(registry: &RefCell<Registry>, id: SignalId) {
+        let persisted = {
+            let mut registry = registry.borrow_mut();
+            let Some(key) = registry.keys.remove(&id) else {
+                return;
+            };
+            registry.signals.remove_entry(&key)
+        };
+
+        // the registry is not borrowed while the value is serialized
+        if let Some((key, persisted)) = persisted {
+            if let Ok(value) = (persisted.save)() {
+                registry.borrow_mut().unclaimed.insert(key, value);
+            }
+        }
+    }
+}
+
+impl<T> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    /// Includes the signal in [`Runtime::save_json`] under `key`. If a value
+    /// for `key` was restored before the signal existed, the signal takes it;
+    /// a value that no longer deserializes keeps the current one.
+    pub fn persist(self, key: impl Into<String>) -> Self {
+        let key = key.into();
+        let state = self.runtime().state();
+
+        let restored = {
+            let mut registry = state.persisted().borrow_mut();
+            assert!(
+                !registry.signals.contains_key(&key),
+                "persistent key {:?} is already used",
+                key
+            );
+
+            registry.keys.insert(self.id(), key.clone());
+            registry.signals.insert(
+                key.clone(),
+                Persisted {
+                    save: Rc::new(move || self.with_untracked(|value| serde_json::to_value(value))),
+                    restore: Rc::new(move |value| {
+                        self.set(serde_json::from_value(value)?);
+                        Ok(())
+                    }),
+                },
+            );
+            registry.unclaimed.remove(&key)
+        };
+
+        if let Some(value) = restored.and_then(|value| serde_json::from_value(value).ok()) {
+            self.set(value);
+        }
+
+        self
+    }
+}
+
+impl Runtime {
+    /// Serializes every persistent signal into a JSON object keyed by the
+    /// persistent keys. Restored values that no signal has claimed yet are
+    /// kept, so they survive the next save.
+    pub fn save_json(&self) -> serde_json::Result<String> {
+        let (mut snapshot, saves) = {
+            let state = self.state();
+            let registry = state.persisted().borrow();
+            let saves = registry
+                .signals
+                .iter()
+                .map(|(key, persisted)| (key.clone(), persisted.save.clone()))
+                .collect::<Vec<_>>();
+            (registry.unclaimed.clone(), saves)
+        };
+
+        for (key, save) in saves {
+            snapshot.insert(key, save()?);
+        }
+
+        serde_json::to_string_pretty(&snapshot)
+    }
+
+    /// Sets every persistent signal to its value in `json`, as written by
+    /// [`Runtime::save_json`], in one batch. Values of signals that do not
+    /// exist yet are applied when they are created.
+    pub fn restore_json(&self, json: &str) -> serde_json::Result<()> {
+        let snapshot: BTreeMap<String, Value> = serde_json::from_str(json)?;
+
+        let restores = {
+            let state = self.state();
+            let mut registry = state.persisted().borrow_mut();
+            let mut restores = Vec::new();
+
+            for (key, value) in snapshot {
+                match registry.signals.get(&key) {
+                    Some(persisted) => restores.push((persisted.restore.clone(), value)),
+                    None => {
+                        registry.unclaimed.insert(key, value);
+                    }
+                }
+            }
+            restores
+        };
+
+        self.batch(|| {
+            restores
+                .into_iter()
+                .map(|(restore, value)| restore(value))
+                .fold(Ok(()), Result::and)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use crate::Runtime;
+
+    #[test]
+    fn snapshot_restores_into_a_new_runtime() {
+        let cx = Runtime::new();
+        let camera = cx.create_signal((0.0, 0.0)).persist("camera");
+        let title = cx.create_signal("untitled".to_string()).persist("title");
+        cx.create_signal(0).named("not persisted");
+
+        camera.set((1.5, -2.0));
+        title.set("sketch".to_string());
+        let json = cx.save_json().unwrap();
+        cx.dispose();
+
+        let cx = Runtime::new();
+        cx.restore_json(&json).unwrap();
+        // created after the restore, e.g. on the next start
+        let camera = cx.create_signal((0.0, 0.0)).persist("camera");
+        let title = cx.create_signal(String::new()).persist("title");
+
+        assert_eq!(camera.get(), (1.5, -2.0));
+        assert_eq!(title.get(), "sketch");
+        assert_eq!(cx.save_json().unwrap(), json);
+    }
+
+    #[test]
+    fn disposed_signals_are_still_saved() {
+        let cx = Runtime::new();
+        let open_window = || {
+            cx.create_root(|scope| {
+                let width = cx.create_signal(800).persist("window.width");
+                (width, scope)
+            })
+        };
+
+        let (width, window) = open_window();
+        width.set(1024);
+        window.dispose();
+
+        let json = cx.save_json().unwrap();
+        assert!(json.contains(r#""window.width": 1024"#));
+
+        let (width, _) = open_window();
+        assert_eq!(width.get(), 1024);
+    }
+
+    #[test]
+    fn restore_updates_live_signals_in_one_batch() {
+        let cx = Runtime::new();
+        let width = cx.create_signal(800).persist("width");
+        let height = cx.create_signal(600).persist("height");
+        let runs = Rc::new(Cell::new(0));
+
+        cx.create_effect({
+            let runs = runs.clone();
+            move || {
+                width.get();
+                height.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+
+        cx.restore_json(r#"{"width": 1024, "height": 768, "unknown": true}"#)
+            .unwrap();
+        assert_eq!((width.get(), height.get()), (1024, 768));
+        assert_eq!(runs.get(), 2);
+
+        // the unknown key is kept for a signal created later
+        assert!(cx.save_json().unwrap().contains(r#""unknown": true"#));
+
+        assert!(cx.restore_json(r#"{"width": "wide"}"#).is_err());
+        assert_eq!(width.get(), 1024);
+    }
+}
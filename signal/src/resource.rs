@@ -0,0 +1,270 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::rc::Rc;
+
+use crate::{ReadSignal, Runtime, Signal, SignalGet};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState<T, E> {
+    Loading,
+    Ready(T),
+    Error(E),
+}
+
+/// Value loaded by a future, see [`Runtime::create_resource`].
+pub struct Resource<T, E> {
+    state: Signal<ResourceState<T, E>>,
+    trigger: Signal<()>,
+}
+
+impl<T, E> Clone for Resource<T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for Resource<T, E> {}
+
+impl Runtime {
+    /// Runs `fetcher` with the value of `source` on the runtime's executor
+    /// and again every time `source` changes. Signals read by `fetcher` itself
+    /// are not tracked. Results of fetches started before the latest one are
+    /// ignored.
+    pub fn create_resource<S, T, E, Fut>(
+        &self,
+        source: impl SignalGet<S> + 'static,
+        fetcher: impl Fn(S) -> Fut + 'static,
+    ) -> Resource<T, E>
+    where
+        T: 'static,
+        E: 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        let cx = *self;
+        let state = self.create_signal(ResourceState::Loading);
+        let trigger = self.create_signal(());
+
+        self.create_effect(move || {
+            trigger.get();
+            // only the source is tracked, not what the fetcher reads
+            let value = source.get();
+            let future = cx.untrack(|| fetcher(value));
+
+            // cleared when the effect re-runs or is disposed
+            let current = Rc::new(Cell::new(true));
+            cx.on_cleanup({
+                let current = current.clone();
+                move || current.set(false)
+            });
+
+            if !state.with_untracked(|state| matches!(state, ResourceState::Loading)) {
+                state.set(ResourceState::Loading);
+            }
+
+            cx.spawn_local(async move {
+                let result = future.await;
+
+                if current.get() {
+                    state.set(match result {
+                        Ok(value) => ResourceState::Ready(value),
+                        Err(error) => ResourceState::Error(error),
+                    });
+                }
+            });
+        });
+
+        Resource { state, trigger }
+    }
+}
+
+impl<T, E> Resource<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    pub fn state(&self) -> ReadSignal<ResourceState<T, E>> {
+        self.state.read_only()
+    }
+
+    pub fn with<R>(&self, f: impl FnOnce(&ResourceState<T, E>) -> R) -> R {
+        self.state.with(f)
+    }
+
+    pub fn loading(&self) -> bool {
+        self.with(|state| matches!(state, ResourceState::Loading))
+    }
+
+    /// Starts the fetch again with the current source value.
+    pub fn refetch(&self) {
+        self.trigger.set(());
+    }
+}
+
+impl<T, E> Resource<T, E>
+where
+    T: Clone + 'static,
+    E: 'static,
+{
+    /// The loaded value, or `None` while loading or after an error.
+    pub fn get(&self) -> Option<T> {
+        self.with(|state| match state {
+            ResourceState::Ready(value) => Some(value.clone()),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll, Waker};
+
+    use super::ResourceState;
+    use crate::{LocalExecutor, Runtime};
+
+    type Slot<T> = Rc<RefCell<(Option<T>, Option<Waker>)>>;
+
+    /// Future completed by hand from the test.
+    struct Deferred<T>(Slot<T>);
+
+    impl<T> Future for Deferred<T> {
+        type Output = T;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+            let mut slot = self.0.borrow_mut();
+            match slot.0.take() {
+                Some(value) => Poll::Ready(value),
+                None => {
+                    slot.1 = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    fn complete<T>(slot: &Slot<T>, value: T) {
+        let mut slot = slot.borrow_mut();
+        slot.0 = Some(value);
+        if let Some(waker) = slot.1.take() {
+            waker.wake();
+        }
+    }
+
+    type Requests = Rc<RefCell<Vec<(u32, Slot<Result<String, String>>)>>>;
+
+    fn setup() -> (Runtime, LocalExecutor, Requests) {
+        let cx = Runtime::new();
+        let executor = LocalExecutor::new();
+        cx.set_executor(executor.clone());
+
+        (cx, executor, Default::default())
+    }
+
+    #[test]
+    fn resource_goes_from_loading_to_ready() {
+        let (cx, executor, requests) = setup();
+        let id = cx.create_signal(1);
+
+        let resource = cx.create_resource(id, {
+            let requests = requests.clone();
+            move |id| {
+                let slot = Slot::default();
+                requests.borrow_mut().push((id, slot.clone()));
+                Deferred(slot)
+            }
+        });
+
+        executor.run_until_stalled();
+        assert!(resource.loading());
+
+        complete(&requests.borrow()[0].1, Ok("one".to_string()));
+        assert_eq!(executor.run_until_stalled(), 0);
+        assert_eq!(resource.get(), Some("one".to_string()));
+
+        resource.refetch();
+        assert!(resource.loading());
+        complete(&requests.borrow()[1].1, Err("offline".to_string()));
+        executor.run_until_stalled();
+        assert_eq!(
+            resource.state().get(),
+            ResourceState::Error("offline".to_string())
+        );
+    }
+
+    #[test]
+    fn stale_results_are_ignored() {
+        let (cx, executor, requests) = setup();
+        let id = cx.create_signal(1);
+
+        let resource = cx.create_resource(id, {
+            let requests = requests.clone();
+            move |id| {
+                let slot = Slot::default();
+                requests.borrow_mut().push((id, slot.clone()));
+                Deferred(slot)
+            }
+        });
+        executor.run_until_stalled();
+
+        id.set(2);
+        executor.run_until_stalled();
+        assert_eq!(requests.borrow().len(), 2);
+
+        // the first request finishes last but must not win
+        complete(&requests.borrow()[1].1, Ok("two".to_string()));
+        complete(&requests.borrow()[0].1, Ok("one".to_string()));
+        executor.run_until_stalled();
+        assert_eq!(resource.get(), Some("two".to_string()));
+    }
+
+    #[test]
+    fn signals_read_by_the_fetcher_do_not_refetch() {
+        let (cx, executor, requests) = setup();
+        let id = cx.create_signal(1);
+        let token = cx.create_signal("a");
+
+        cx.create_resource(id, {
+            let requests = requests.clone();
+            move |id| {
+                token.get();
+                let slot = Slot::default();
+                requests.borrow_mut().push((id, slot.clone()));
+                Deferred(slot)
+            }
+        });
+        executor.run_until_stalled();
+
+        token.set("b");
+        executor.run_until_stalled();
+        assert_eq!(requests.borrow().len(), 1);
+
+        id.set(2);
+        executor.run_until_stalled();
+        assert_eq!(requests.borrow().len(), 2);
+    }
+
+    #[test]
+    fn results_after_disposal_are_ignored() {
+        let (cx, executor, requests) = setup();
+
+        let scope = cx.create_root(|scope| {
+            cx.create_resource(move || 1, {
+                let requests = requests.clone();
+                move |id| {
+                    let slot = Slot::default();
+                    requests.borrow_mut().push((id, slot.clone()));
+                    Deferred(slot)
+                }
+            });
+            scope
+        });
+        executor.run_until_stalled();
+
+        scope.dispose();
+        complete(&requests.borrow()[0].1, Ok("one".to_string()));
+        assert_eq!(executor.run_until_stalled(), 0);
+    }
+}
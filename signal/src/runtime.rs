@@ -0,0 +1,1155 @@
+use std::any::{Any, TypeId};
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::arena::{Arena, Key};
+use crate::clock::Clock;
+use crate::debug::{EffectInfo, Graph, SignalInfo, TraceEvent, TraceNode};
+use crate::error::ReactiveError;
+use crate::executor::{Executor, LocalFuture};
+#[cfg(feature = "serde")]
+use crate::persist::Registry;
+use crate::{effect::Effect, memo::Memo, scope::Scope, signal::Signal};
+
+thread_local! {
+    static RUNTIMES: RefCell<Arena<Rc<RuntimeState>>> = RefCell::new(Arena::default());
+}
+
+/// Handle to a reactive runtime.
+///
+/// The handle is `Copy`, so it can be captured by any number of closures.
+/// Everything created in the runtime is freed by [`Runtime::dispose`]. There
+/// is no owner that disposes it on drop: a runtime that is never disposed
+/// lives until its thread exits.
+///
+/// The runtime belongs to the thread that created it, so the handle and
+/// everything holding it are neither `Send` nor `Sync`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Runtime {
+    key: Key,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runtime {
+    pub fn new() -> Self {
+        let state = Rc::new(RuntimeState::new());
+
+        let key = RUNTIMES.with(|runtimes| runtimes.borrow_mut().insert(state));
+
+        Runtime {
+            key,
+            _not_send: PhantomData,
+        }
+    }
+
+    pub(crate) fn state(&self) -> Rc<RuntimeState> {
+        self.try_state().expect("runtime has been disposed")
+    }
+
+    pub(crate) fn try_state(&self) -> Result<Rc<RuntimeState>, ReactiveError> {
+        RUNTIMES
+            .with(|runtimes| runtimes.borrow().get(self.key).cloned())
+            .ok_or(ReactiveError::Disposed)
+    }
+
+    pub fn create_signal<T>(&self, value: T) -> Signal<T>
+    where
+        T: 'static,
+    {
+        let id = self.state().push_signal(value);
+
+        Signal::new(*self, id)
+    }
+
+    pub fn create_memo<T>(&self, f: impl Fn() -> T + 'static) -> Memo<T>
+    where
+        T: PartialEq + 'static,
+    {
+        // the value is filled in by the first run of the memo's effect
+        let state = self.state();
+        let id = state.push_signal(None::<T>);
+
+        let cx = *self;
+        let effect_id = state.push_effect(move || {
+            let value = f();
+
+            let state = cx.state();
+            let changed = state.update_value(id, |wrapper: &mut Option<T>| {
+                if wrapper.as_ref() == Some(&value) {
+                    false
+                } else {
+                    *wrapper = Some(value);
+                    true
+                }
+            });
+
+            if changed {
+                state.notify(id);
+            }
+        });
+        if let Some(signal) = state.signals.borrow_mut().get_mut(id.0) {
+            signal.memo = Some(effect_id);
+        }
+        if let Some(effect) = state.effects.borrow_mut().get_mut(effect_id.0) {
+            effect.memo = true;
+        }
+        state.run_effect(effect_id);
+        state.report(state.flush());
+
+        Memo::new(*self, id)
+    }
+
+    pub fn create_effect(&self, f: impl Fn() + 'static) -> Effect {
+        let state = self.state();
+        let id = state.push_effect(f);
+
+        state.run_effect(id);
+        state.report(state.flush());
+
+        Effect::new(*self, id)
+    }
+
+    /// Runs `f` without subscribing the running effect to anything it reads.
+    pub fn untrack<R>(&self, f: impl FnOnce() -> R) -> R {
+        let state = self.state();
+
+        let prev_running_effect = state.running_effect.take();
+        let result = f();
+        state.running_effect.set(prev_running_effect);
+
+        result
+    }
+
+    /// Runs `f` and defers every effect it triggers until the outermost batch
+    /// ends, so each effect runs at most once for all the changes made.
+    pub fn batch<R>(&self, f: impl FnOnce() -> R) -> R {
+        let state = self.state();
+
+        let (result, flushed) = state.batch(f);
+        state.report(flushed);
+
+        result
+    }
+
+    pub fn try_batch<R>(&self, f: impl FnOnce() -> R) -> Result<R, ReactiveError> {
+        let (result, flushed) = self.state().batch(f);
+
+        flushed.map(|_| result)
+    }
+
+    /// Sets how many times a single effect may run within one update before
+    /// the update is aborted with [`ReactiveError::CycleDetected`].
+    pub fn set_max_iterations(&self, max_iterations: usize) {
+        self.state().max_iterations.set(max_iterations);
+    }
+
+    /// Takes the error of the last update that was aborted outside of
+    /// `try_*` calls.
+    pub fn take_error(&self) -> Option<ReactiveError> {
+        self.state().error.take()
+    }
+
+    /// Sets the executor that runs the futures of resources.
+    pub fn set_executor(&self, executor: impl Executor + 'static) {
+        self.state().executor.replace(Some(Rc::new(executor)));
+    }
+
+    pub(crate) fn spawn_local(&self, future: impl Future<Output = ()> + 'static) {
+        let executor = self.state().executor.borrow().clone();
+        let future: LocalFuture = Box::pin(future);
+
+        executor
+            .expect("no executor set, see `Runtime::set_executor`")
+            .spawn_local(future);
+    }
+
+    /// Sets the clock that drives debounced, throttled and interval signals.
+    pub fn set_clock(&self, clock: impl Clock + 'static) {
+        self.state().clock.replace(Some(Rc::new(clock)));
+    }
+
+    pub(crate) fn clock(&self) -> Rc<dyn Clock> {
+        let clock = self.state().clock.borrow().clone();
+
+        clock.expect("no clock set, see `Runtime::set_clock`")
+    }
+
+    /// Runs `f` in a new scope that is only disposed explicitly or together
+    /// with the runtime.
+    pub fn create_root<R>(&self, f: impl FnOnce(Scope) -> R) -> R {
+        let state = self.state();
+        let id = state.push_scope(Some(state.root));
+
+        let scope = Scope::new(*self, id);
+        state.with_owner(id, || f(scope))
+    }
+
+    /// Runs `f` in a new scope that is disposed together with the current one.
+    pub fn create_scope<R>(&self, f: impl FnOnce(Scope) -> R) -> R {
+        let state = self.state();
+        let id = state.push_scope(Some(state.owner.get()));
+
+        let scope = Scope::new(*self, id);
+        state.with_owner(id, || f(scope))
+    }
+
+    /// Registers `f` to run when the current scope is disposed.
+    pub fn on_cleanup(&self, f: impl FnOnce() + 'static) {
+        let state = self.state();
+        state.push_cleanup(state.owner.get(), Box::new(f));
+    }
+
+    /// Disposes every scope, effect and signal of the runtime and frees it.
+    /// Its slot is reused by later runtimes, but stale handles never reach
+    /// them. Disposing it again is a no-op.
+    pub fn dispose(self) {
+        let Ok(state) = self.try_state() else {
+            return;
+        };
+        state.dispose_scope(state.root);
+
+        let state = RUNTIMES.with(|runtimes| runtimes.borrow_mut().remove(self.key));
+        drop(state);
+    }
+}
+
+type SignalValue = Rc<RefCell<dyn Any>>;
+type EffectFn = Rc<dyn Fn()>;
+type Tracer = Rc<dyn Fn(&TraceEvent)>;
+
+struct SignalNode {
+    value: SignalValue,
+    // effects that read the signal on their last run
+    subscribers: Vec<EffectId>,
+    // effect run that subscribed last, so reading the signal again in the
+    // same run does not record it twice
+    tracked_by: Option<(EffectId, usize)>,
+    // effect computing the value when the signal backs a memo
+    memo: Option<EffectId>,
+    name: Option<String>,
+}
+
+struct EffectNode {
+    f: EffectFn,
+    // owns everything the effect creates, reset before every run
+    scope: ScopeId,
+    // signals read on the last run
+    sources: Vec<SignalId>,
+    // one more than the highest memo it read, so memos run before their readers
+    height: usize,
+    // computes the value of a memo
+    memo: bool,
+    name: Option<String>,
+    runs: usize,
+}
+
+pub(crate) struct RuntimeState {
+    signals: RefCell<Arena<SignalNode>>,
+    effects: RefCell<Arena<EffectNode>>,
+    scopes: RefCell<Arena<ScopeState>>,
+    running_effect: Cell<Option<EffectId>>,
+    pending_effects: RefCell<BTreeSet<(usize, EffectId)>>,
+    batch_depth: Cell<usize>,
+    flushing: Cell<bool>,
+    max_iterations: Cell<usize>,
+    error: RefCell<Option<ReactiveError>>,
+    executor: RefCell<Option<Rc<dyn Executor>>>,
+    clock: RefCell<Option<Rc<dyn Clock>>>,
+    tracer: RefCell<Option<Tracer>>,
+    // why each pending effect was queued, only collected while tracing
+    pending_causes: RefCell<HashMap<EffectId, Vec<SignalId>>>,
+    #[cfg(feature = "serde")]
+    persisted: RefCell<Registry>,
+    owner: Cell<ScopeId>,
+    root: ScopeId,
+}
+
+#[derive(Default)]
+struct ScopeState {
+    parent: Option<ScopeId>,
+    children: Vec<ScopeId>,
+    signals: Vec<SignalId>,
+    effects: Vec<EffectId>,
+    cleanups: Vec<Box<dyn FnOnce()>>,
+    contexts: HashMap<TypeId, Rc<dyn Any>>,
+}
+
+impl RuntimeState {
+    fn new() -> Self {
+        let mut scopes = Arena::default();
+        let root = ScopeId(scopes.insert(ScopeState::default()));
+
+        RuntimeState {
+            signals: Default::default(),
+            effects: Default::default(),
+            scopes: RefCell::new(scopes),
+            running_effect: Default::default(),
+            pending_effects: Default::default(),
+            batch_depth: Default::default(),
+            flushing: Default::default(),
+            max_iterations: Cell::new(100),
+            error: Default::default(),
+            executor: Default::default(),
+            clock: Default::default(),
+            tracer: Default::default(),
+            pending_causes: Default::default(),
+            #[cfg(feature = "serde")]
+            persisted: Default::default(),
+            owner: Cell::new(root),
+            root,
+        }
+    }
+
+    pub(crate) fn push_signal<T: 'static>(&self, value: T) -> SignalId {
+        let id = SignalId(self.signals.borrow_mut().insert(SignalNode {
+            value: Rc::new(RefCell::new(value)),
+            subscribers: Vec::new(),
+            tracked_by: None,
+            memo: None,
+            name: None,
+        }));
+
+        if let Some(scope) = self.scopes.borrow_mut().get_mut(self.owner.get().0) {
+            scope.signals.push(id);
+        }
+
+        id
+    }
+
+    fn push_effect(&self, f: impl Fn() + 'static) -> EffectId {
+        let scope = self.push_scope(Some(self.owner.get()));
+
+        let id = EffectId(self.effects.borrow_mut().insert(EffectNode {
+            f: Rc::new(f),
+            scope,
+            sources: Vec::new(),
+            height: 0,
+            memo: false,
+            name: None,
+            runs: 0,
+        }));
+
+        if let Some(scope) = self.scopes.borrow_mut().get_mut(self.owner.get().0) {
+            scope.effects.push(id);
+        }
+
+        id
+    }
+
+    fn push_scope(&self, parent: Option<ScopeId>) -> ScopeId {
+        let mut scopes = self.scopes.borrow_mut();
+        let id = ScopeId(scopes.insert(ScopeState {
+            parent,
+            ..Default::default()
+        }));
+
+        if let Some(parent) = parent.and_then(|parent| scopes.get_mut(parent.0)) {
+            parent.children.push(id);
+        }
+
+        id
+    }
+
+    pub(crate) fn push_cleanup(&self, scope: ScopeId, f: Box<dyn FnOnce()>) {
+        if let Some(scope) = self.scopes.borrow_mut().get_mut(scope.0) {
+            scope.cleanups.push(f);
+        }
+    }
+
+    pub(crate) fn owner(&self) -> ScopeId {
+        self.owner.get()
+    }
+
+    pub(crate) fn provide_context<T: 'static>(&self, scope: ScopeId, value: T) {
+        if let Some(scope) = self.scopes.borrow_mut().get_mut(scope.0) {
+            scope.contexts.insert(TypeId::of::<T>(), Rc::new(value));
+        }
+    }
+
+    /// Looks the context up in `scope` and then in its ancestors.
+    pub(crate) fn use_context<T: Clone + 'static>(&self, scope: ScopeId) -> Option<T> {
+        let scopes = self.scopes.borrow();
+        let mut next = Some(scope);
+
+        while let Some(scope) = next.and_then(|scope| scopes.get(scope.0)) {
+            if let Some(value) = scope.contexts.get(&TypeId::of::<T>()) {
+                return value.downcast_ref::<T>().cloned();
+            }
+            next = scope.parent;
+        }
+
+        None
+    }
+
+    pub(crate) fn with_owner<R>(&self, owner: ScopeId, f: impl FnOnce() -> R) -> R {
+        assert!(
+            self.scopes.borrow().contains(owner.0),
+            "scope has been disposed"
+        );
+
+        let prev_owner = self.owner.replace(owner);
+        let result = f();
+        self.owner.set(prev_owner);
+
+        result
+    }
+
+    pub(crate) fn dispose_scope(&self, id: ScopeId) {
+        self.reset_scope(id);
+
+        let Some(scope) = self.scopes.borrow_mut().remove(id.0) else {
+            return;
+        };
+
+        if let Some(parent) = scope.parent {
+            if let Some(parent) = self.scopes.borrow_mut().get_mut(parent.0) {
+                parent.children.retain(|child| *child != id);
+            }
+        }
+    }
+
+    /// Disposes everything owned by the scope but keeps the scope itself.
+    fn reset_scope(&self, id: ScopeId) {
+        let scope = match self.scopes.borrow_mut().get_mut(id.0) {
+            Some(scope) => std::mem::replace(
+                scope,
+                ScopeState {
+                    parent: scope.parent,
+                    ..Default::default()
+                },
+            ),
+            None => return,
+        };
+
+        for child in scope.children.into_iter().rev() {
+            self.dispose_scope(child);
+        }
+
+        // cleanups can still read the signals of the scope
+        for cleanup in scope.cleanups.into_iter().rev() {
+            cleanup();
+        }
+
+        for effect_id in scope.effects {
+            let effect = self.effects.borrow_mut().remove(effect_id.0);
+            if let Some(effect) = &effect {
+                self.unsubscribe(effect_id, &effect.sources);
+            }
+            drop(effect);
+        }
+
+        for signal_id in scope.signals {
+            // still reads the value, so it goes before the removal
+            #[cfg(feature = "serde")]
+            Registry::forget(&self.persisted, signal_id);
+            let signal = self.signals.borrow_mut().remove(signal_id.0);
+            drop(signal);
+        }
+    }
+
+    // the value is cloned out so `f` is free to create and dispose signals
+    fn value(&self, id: SignalId) -> Result<SignalValue, ReactiveError> {
+        self.signals
+            .borrow()
+            .get(id.0)
+            .map(|signal| signal.value.clone())
+            .ok_or(ReactiveError::Disposed)
+    }
+
+    pub(crate) fn with_value<T: 'static, R>(&self, id: SignalId, f: impl FnOnce(&T) -> R) -> R {
+        self.try_with_value(id, f)
+            .expect("signal has been disposed")
+    }
+
+    pub(crate) fn try_with_value<T: 'static, R>(
+        &self,
+        id: SignalId,
+        f: impl FnOnce(&T) -> R,
+    ) -> Result<R, ReactiveError> {
+        let value = self.value(id)?;
+        let value = value.borrow();
+
+        // a live id always points to a value of its own type, ids are
+        // generational and never leave the thread of their runtime
+        Ok(f(value.downcast_ref::<T>().unwrap()))
+    }
+
+    pub(crate) fn update_value<T: 'static, R>(
+        &self,
+        id: SignalId,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> R {
+        self.try_update_value(id, f)
+            .expect("signal has been disposed")
+    }
+
+    pub(crate) fn try_update_value<T: 'static, R>(
+        &self,
+        id: SignalId,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Result<R, ReactiveError> {
+        let value = self.value(id)?;
+        let mut value = value.borrow_mut();
+
+        Ok(f(value.downcast_mut::<T>().unwrap()))
+    }
+
+    /// Runs the effect right away. Signals it sets only queue their
+    /// subscribers, which run on the next flush.
+    fn run_effect(&self, effect_id: EffectId) {
+        let Some((effect, scope)) = self
+            .effects
+            .borrow_mut()
+            .get_mut(effect_id.0)
+            .map(|effect| {
+                effect.runs += 1;
+                (effect.f.clone(), effect.scope)
+            })
+        else {
+            return;
+        };
+
+        let causes = self.pending_causes.borrow_mut().remove(&effect_id);
+        self.trace(|| TraceEvent::EffectRun {
+            effect: self.effect_trace_node(effect_id),
+            triggered_by: causes
+                .unwrap_or_default()
+                .into_iter()
+                .map(|signal_id| self.signal_trace_node(signal_id))
+                .collect(),
+        });
+
+        // children and dependencies are created again on every run
+        self.reset_scope(scope);
+        self.cleanup_sources(effect_id);
+
+        let prev_running_effect = self.running_effect.replace(Some(effect_id));
+        self.batch_depth.set(self.batch_depth.get() + 1);
+
+        // run effect
+        self.with_owner(scope, || effect());
+
+        self.batch_depth.set(self.batch_depth.get() - 1);
+        self.running_effect.set(prev_running_effect);
+
+        let height = self.effect_height(effect_id);
+        if let Some(effect) = self.effects.borrow_mut().get_mut(effect_id.0) {
+            effect.height = height;
+        }
+    }
+
+    /// Brings the memo up to date before it is read: pending memos below its
+    /// height run first, as it may read them, and then its own effect if that
+    /// is pending by now.
+    pub(crate) fn refresh_memo(&self, id: SignalId) {
+        let Some(memo) = self
+            .signals
+            .borrow()
+            .get(id.0)
+            .and_then(|signal| signal.memo)
+        else {
+            return;
+        };
+
+        loop {
+            let next = {
+                let effects = self.effects.borrow();
+                let Some(height) = effects.get(memo.0).map(|effect| effect.height) else {
+                    return;
+                };
+                let is_memo = |effect_id: EffectId| {
+                    effects.get(effect_id.0).is_some_and(|effect| effect.memo)
+                };
+
+                self.pending_effects
+                    .borrow()
+                    .iter()
+                    .take_while(|(h, _)| *h <= height)
+                    .find(|&&(h, effect_id)| effect_id == memo || h < height && is_memo(effect_id))
+                    .copied()
+            };
+            let Some(next) = next else {
+                return;
+            };
+
+            self.pending_effects.borrow_mut().remove(&next);
+            self.run_effect(next.1);
+        }
+    }
+
+    fn effect_height(&self, effect_id: EffectId) -> usize {
+        let signals = self.signals.borrow();
+        let effects = self.effects.borrow();
+
+        let source_height = |source: &SignalId| {
+            signals
+                .get(source.0)
+                .and_then(|signal| signal.memo)
+                .and_then(|memo| effects.get(memo.0))
+                .map_or(0, |memo| memo.height)
+        };
+
+        effects
+            .get(effect_id.0)
+            .and_then(|effect| effect.sources.iter().map(source_height).max())
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn cleanup_sources(&self, effect_id: EffectId) {
+        let sources = self
+            .effects
+            .borrow_mut()
+            .get_mut(effect_id.0)
+            .map(|effect| std::mem::take(&mut effect.sources));
+
+        if let Some(sources) = sources {
+            self.unsubscribe(effect_id, &sources);
+        }
+    }
+
+    fn unsubscribe(&self, effect_id: EffectId, sources: &[SignalId]) {
+        let mut signals = self.signals.borrow_mut();
+
+        for source in sources {
+            if let Some(signal) = signals.get_mut(source.0) {
+                signal.subscribers.retain(|sub| *sub != effect_id);
+            }
+        }
+    }
+
+    pub(crate) fn track(&self, id: SignalId) {
+        let Some(running_effect) = self.running_effect.get() else {
+            return;
+        };
+
+        let mut effects = self.effects.borrow_mut();
+        let Some(effect) = effects.get_mut(running_effect.0) else {
+            return;
+        };
+        let mut signals = self.signals.borrow_mut();
+        let Some(signal) = signals.get_mut(id.0) else {
+            return;
+        };
+
+        let run = (running_effect, effect.runs);
+        if signal.tracked_by != Some(run) {
+            signal.tracked_by = Some(run);
+            signal.subscribers.push(running_effect);
+            effect.sources.push(id);
+        }
+    }
+
+    pub(crate) fn notify(&self, id: SignalId) {
+        let subs = self
+            .signals
+            .borrow()
+            .get(id.0)
+            .map(|signal| signal.subscribers.clone())
+            .unwrap_or_default();
+
+        self.trace(|| TraceEvent::SignalChanged {
+            signal: self.signal_trace_node(id),
+            subscribers: subs
+                .iter()
+                .map(|effect_id| self.effect_trace_node(*effect_id))
+                .collect(),
+        });
+
+        let tracing = self.tracer.borrow().is_some();
+        let effects = self.effects.borrow();
+        let mut pending = self.pending_effects.borrow_mut();
+
+        for sub in subs {
+            if let Some(effect) = effects.get(sub.0) {
+                pending.insert((effect.height, sub));
+
+                if tracing {
+                    let mut causes = self.pending_causes.borrow_mut();
+                    causes.entry(sub).or_default().push(id);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn set_signal_name(&self, id: SignalId, name: String) {
+        if let Some(signal) = self.signals.borrow_mut().get_mut(id.0) {
+            signal.name = Some(name);
+        }
+    }
+
+    pub(crate) fn set_effect_name(&self, id: EffectId, name: String) {
+        if let Some(effect) = self.effects.borrow_mut().get_mut(id.0) {
+            effect.name = Some(name);
+        }
+    }
+
+    pub(crate) fn effect_runs(&self, id: EffectId) -> usize {
+        self.effects
+            .borrow()
+            .get(id.0)
+            .map_or(0, |effect| effect.runs)
+    }
+
+    pub(crate) fn set_tracer(&self, tracer: Option<Tracer>) {
+        self.tracer.replace(tracer);
+        self.pending_causes.borrow_mut().clear();
+    }
+
+    fn trace(&self, event: impl FnOnce() -> TraceEvent) {
+        let tracer = self.tracer.borrow().clone();
+
+        if let Some(tracer) = tracer {
+            tracer(&event());
+        }
+    }
+
+    fn signal_trace_node(&self, id: SignalId) -> TraceNode {
+        TraceNode {
+            id: id.0.index(),
+            name: self
+                .signals
+                .borrow()
+                .get(id.0)
+                .and_then(|signal| signal.name.clone()),
+        }
+    }
+
+    fn effect_trace_node(&self, id: EffectId) -> TraceNode {
+        TraceNode {
+            id: id.0.index(),
+            name: self
+                .effects
+                .borrow()
+                .get(id.0)
+                .and_then(|effect| effect.name.clone()),
+        }
+    }
+
+    /// Snapshot of every live signal and effect and the edges between them.
+    pub(crate) fn graph(&self) -> Graph {
+        let signals = self.signals.borrow();
+        let effects = self.effects.borrow();
+
+        let memos = signals
+            .iter()
+            .filter_map(|(id, signal)| Some((signal.memo?, id.index())))
+            .collect::<HashMap<_, _>>();
+
+        let signals = signals
+            .iter()
+            .map(|(id, signal)| {
+                let mut subscribers = signal
+                    .subscribers
+                    .iter()
+                    .map(|effect_id| effect_id.0.index())
+                    .collect::<Vec<_>>();
+                subscribers.sort_unstable();
+                subscribers.dedup();
+
+                SignalInfo {
+                    id: id.index(),
+                    name: signal.name.clone(),
+                    subscribers,
+                }
+            })
+            .collect();
+
+        let effects = effects
+            .iter()
+            .map(|(id, effect)| EffectInfo {
+                id: id.index(),
+                name: effect.name.clone(),
+                runs: effect.runs,
+                memo: memos.get(&EffectId(id)).copied(),
+            })
+            .collect();
+
+        Graph { signals, effects }
+    }
+
+    pub(crate) fn batch<R>(&self, f: impl FnOnce() -> R) -> (R, Result<(), ReactiveError>) {
+        self.batch_depth.set(self.batch_depth.get() + 1);
+        let result = f();
+        self.batch_depth.set(self.batch_depth.get() - 1);
+
+        (result, self.flush())
+    }
+
+    /// Runs pending effects lowest height first, so every memo is up to date
+    /// before anything that reads it runs. Does nothing inside a batch or an
+    /// effect; the outermost one flushes when it ends.
+    pub(crate) fn flush(&self) -> Result<(), ReactiveError> {
+        if self.batch_depth.get() > 0 || self.flushing.get() {
+            return Ok(());
+        }
+
+        self.flushing.set(true);
+
+        let max_iterations = self.max_iterations.get();
+        let mut runs = HashMap::<EffectId, usize>::new();
+
+        let result = loop {
+            let next = self.pending_effects.borrow_mut().pop_first();
+            let Some((_, effect_id)) = next else {
+                break Ok(());
+            };
+
+            let count = runs.entry(effect_id).or_default();
+            *count += 1;
+            if *count > max_iterations {
+                self.pending_effects.borrow_mut().clear();
+                break Err(ReactiveError::CycleDetected {
+                    iterations: max_iterations,
+                });
+            }
+
+            self.run_effect(effect_id);
+        };
+
+        self.flushing.set(false);
+
+        result
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn persisted(&self) -> &RefCell<Registry> {
+        &self.persisted
+    }
+
+    pub(crate) fn report(&self, result: Result<(), ReactiveError>) {
+        if let Err(error) = result {
+            self.error.replace(Some(error));
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct SignalId(Key);
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct EffectId(Key);
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ScopeId(Key);
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use super::Runtime;
+    use crate::ReactiveError;
+
+    #[test]
+    fn effect_drops_dependencies_of_the_branch_not_taken() {
+        let cx = Runtime::new();
+        let flag = cx.create_signal(true);
+        let a = cx.create_signal(1);
+        let b = cx.create_signal(2);
+
+        let runs = Rc::new(Cell::new(0));
+        let seen = Rc::new(Cell::new(0));
+
+        cx.create_effect({
+            let runs = runs.clone();
+            let seen = seen.clone();
+            move || {
+                runs.set(runs.get() + 1);
+                seen.set(if flag.get() { a.get() } else { b.get() });
+            }
+        });
+        assert_eq!((runs.get(), seen.get()), (1, 1));
+
+        // `b` is not read yet
+        b.set(20);
+        assert_eq!((runs.get(), seen.get()), (1, 1));
+
+        flag.set(false);
+        assert_eq!((runs.get(), seen.get()), (2, 20));
+
+        // `a` was dropped by the last run
+        a.set(10);
+        assert_eq!((runs.get(), seen.get()), (2, 20));
+
+        b.set(30);
+        assert_eq!((runs.get(), seen.get()), (3, 30));
+
+        flag.set(true);
+        assert_eq!((runs.get(), seen.get()), (4, 10));
+
+        b.set(40);
+        assert_eq!((runs.get(), seen.get()), (4, 10));
+    }
+
+    #[test]
+    fn memo_drops_dependencies_of_the_branch_not_taken() {
+        let cx = Runtime::new();
+        let flag = cx.create_signal(true);
+        let a = cx.create_signal(1);
+        let b = cx.create_signal(2);
+
+        let computed = Rc::new(Cell::new(0));
+
+        let memo = cx.create_memo({
+            let computed = computed.clone();
+            move || {
+                computed.set(computed.get() + 1);
+                if flag.get() {
+                    a.get()
+                } else {
+                    b.get()
+                }
+            }
+        });
+        assert_eq!((computed.get(), memo.get()), (1, 1));
+
+        flag.set(false);
+        assert_eq!((computed.get(), memo.get()), (2, 2));
+
+        a.set(10);
+        assert_eq!((computed.get(), memo.get()), (2, 2));
+
+        b.set(20);
+        assert_eq!((computed.get(), memo.get()), (3, 20));
+    }
+
+    #[test]
+    fn memo_notifies_only_when_its_value_changes() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let computed = Rc::new(Cell::new(0));
+        let runs = Rc::new(Cell::new(0));
+
+        let parity = cx.create_memo({
+            let computed = computed.clone();
+            move || {
+                computed.set(computed.get() + 1);
+                a.get() % 2
+            }
+        });
+        cx.create_effect({
+            let runs = runs.clone();
+            move || {
+                parity.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!((computed.get(), runs.get()), (1, 1));
+
+        // recomputed to the same value, so the effect is not notified
+        a.set(3);
+        assert_eq!((computed.get(), runs.get()), (2, 1));
+
+        // reading an up-to-date memo does not recompute it
+        assert_eq!(parity.get(), 1);
+        assert_eq!(computed.get(), 2);
+
+        a.set(4);
+        assert_eq!((computed.get(), runs.get(), parity.get()), (3, 2, 0));
+    }
+
+    #[test]
+    fn effect_stays_subscribed_to_signals_read_on_every_run() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let runs = Rc::new(Cell::new(0));
+
+        cx.create_effect({
+            let runs = runs.clone();
+            move || {
+                a.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+
+        a.set(2);
+        a.set(3);
+        assert_eq!(runs.get(), 3);
+    }
+
+    #[test]
+    fn batch_runs_effects_once_after_outermost_batch() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let b = cx.create_signal(2);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        cx.create_effect({
+            let seen = seen.clone();
+            move || seen.borrow_mut().push(a.get() + b.get())
+        });
+
+        cx.batch(|| {
+            a.set(10);
+            cx.batch(|| b.set(20));
+            assert_eq!(*seen.borrow(), [3]);
+            b.set(30);
+        });
+        assert_eq!(*seen.borrow(), [3, 40]);
+
+        a.set(1);
+        b.set(2);
+        assert_eq!(*seen.borrow(), [3, 40, 31, 3]);
+    }
+
+    #[test]
+    fn diamond_is_propagated_without_glitches() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let double = cx.create_memo(move || a.get() * 2);
+        let triple = cx.create_memo(move || a.get() * 3);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        cx.create_effect({
+            let seen = seen.clone();
+            move || {
+                seen.borrow_mut()
+                    .push((a.get(), double.get(), triple.get()))
+            }
+        });
+
+        a.set(2);
+        a.set(3);
+        assert_eq!(*seen.borrow(), [(1, 2, 3), (2, 4, 6), (3, 6, 9)]);
+    }
+
+    #[test]
+    fn chained_memos_run_before_their_readers() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let b = cx.create_memo(move || a.get() + 1);
+        let c = cx.create_memo(move || b.get() + 1);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        // reads the deepest memo first, then its sources
+        cx.create_effect({
+            let seen = seen.clone();
+            move || seen.borrow_mut().push((c.get(), b.get(), a.get()))
+        });
+
+        a.set(10);
+        assert_eq!(*seen.borrow(), [(3, 2, 1), (12, 11, 10)]);
+    }
+
+    #[test]
+    fn memo_read_in_a_batch_has_caught_up() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let b = cx.create_memo(move || a.get() + 1);
+        let c = cx.create_memo(move || b.get() * 2);
+
+        let read = cx.batch(|| {
+            a.set(5);
+            (c.get(), b.get())
+        });
+        assert_eq!(read, (12, 6));
+    }
+
+    #[test]
+    fn memo_read_after_a_write_in_an_effect_has_caught_up() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let double = cx.create_memo(move || a.get() * 2);
+        let trigger = cx.create_signal(0);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        cx.create_effect({
+            let seen = seen.clone();
+            move || {
+                let value = trigger.get();
+                if value > 0 {
+                    a.set(value);
+                }
+                seen.borrow_mut().push((a.get_untracked(), double.get()));
+            }
+        });
+
+        trigger.set(7);
+        assert_eq!(*seen.borrow(), [(1, 2), (7, 14)]);
+        assert_eq!(cx.take_error(), None);
+    }
+
+    #[test]
+    fn effects_can_create_effects_and_dispose_them_on_rerun() {
+        let cx = Runtime::new();
+        let outer = cx.create_signal(0);
+        let inner = cx.create_signal(0);
+        let inner_runs = Rc::new(Cell::new(0));
+        let cleanups = Rc::new(Cell::new(0));
+
+        cx.create_effect({
+            let inner_runs = inner_runs.clone();
+            let cleanups = cleanups.clone();
+            move || {
+                outer.get();
+                let inner_runs = inner_runs.clone();
+                cx.create_effect(move || {
+                    inner.get();
+                    inner_runs.set(inner_runs.get() + 1);
+                });
+                let cleanups = cleanups.clone();
+                cx.on_cleanup(move || cleanups.set(cleanups.get() + 1));
+            }
+        });
+        assert_eq!((inner_runs.get(), cleanups.get()), (1, 0));
+
+        // the first child is disposed before the second one is created
+        outer.set(1);
+        assert_eq!((inner_runs.get(), cleanups.get()), (2, 1));
+
+        inner.set(1);
+        assert_eq!(inner_runs.get(), 3);
+    }
+
+    #[test]
+    fn effects_can_write_signals_read_by_other_effects() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let b = cx.create_signal(0);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        cx.create_effect(move || b.set(a.get() * 10));
+        cx.create_effect({
+            let seen = seen.clone();
+            move || seen.borrow_mut().push(b.get())
+        });
+
+        a.set(2);
+        assert_eq!(*seen.borrow(), [10, 20]);
+        assert_eq!(cx.take_error(), None);
+    }
+
+    #[test]
+    fn effect_writing_its_own_dependency_is_reported_as_cycle() {
+        let cx = Runtime::new();
+        cx.set_max_iterations(10);
+        let count = cx.create_signal(0);
+
+        cx.create_effect(move || count.set(count.get() + 1));
+        assert_eq!(
+            cx.take_error(),
+            Some(ReactiveError::CycleDetected { iterations: 10 })
+        );
+        assert_eq!(count.get(), 11);
+
+        // the runtime keeps working after the aborted update
+        assert_eq!(
+            count.try_set(0),
+            Err(ReactiveError::CycleDetected { iterations: 10 })
+        );
+        assert_eq!(cx.take_error(), None);
+    }
+}
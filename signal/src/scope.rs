@@ -0,0 +1,186 @@
+use crate::runtime::{Runtime, ScopeId};
+
+/// Owner of the signals, effects and child scopes created while it was the
+/// current scope. Disposing it disposes all of them.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Scope {
+    cx: Runtime,
+    id: ScopeId,
+}
+
+impl Scope {
+    pub(crate) fn new(cx: Runtime, id: ScopeId) -> Self {
+        Scope { cx, id }
+    }
+
+    pub(crate) fn id(&self) -> ScopeId {
+        self.id
+    }
+
+    pub fn runtime(&self) -> Runtime {
+        self.cx
+    }
+
+    /// Runs `f` with this scope as the owner of everything it creates.
+    pub fn run<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.cx.state().with_owner(self.id, f)
+    }
+
+    pub fn on_cleanup(&self, f: impl FnOnce() + 'static) {
+        self.cx.state().push_cleanup(self.id, Box::new(f));
+    }
+
+    pub fn dispose(self) {
+        self.cx.state().dispose_scope(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use crate::{ReactiveError, Runtime};
+
+    #[test]
+    fn disposed_scope_stops_its_effects() {
+        let cx = Runtime::new();
+        let count = cx.create_signal(0);
+        let runs = Rc::new(Cell::new(0));
+
+        let scope = cx.create_root(|scope| {
+            cx.create_effect({
+                let runs = runs.clone();
+                move || {
+                    count.get();
+                    runs.set(runs.get() + 1);
+                }
+            });
+            scope
+        });
+
+        count.set(1);
+        assert_eq!(runs.get(), 2);
+
+        scope.dispose();
+
+        count.set(2);
+        assert_eq!(runs.get(), 2);
+    }
+
+    #[test]
+    fn child_scopes_and_cleanups_are_disposed_with_parent() {
+        let cx = Runtime::new();
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let scope = cx.create_root(|scope| {
+            let log = log.clone();
+            cx.on_cleanup({
+                let log = log.clone();
+                move || log.borrow_mut().push("root first")
+            });
+            cx.create_scope(|_| {
+                let log = log.clone();
+                cx.on_cleanup(move || log.borrow_mut().push("child"));
+            });
+            cx.on_cleanup(move || log.borrow_mut().push("root second"));
+            scope
+        });
+
+        assert!(log.borrow().is_empty());
+
+        scope.dispose();
+        assert_eq!(*log.borrow(), ["child", "root second", "root first"]);
+
+        // disposing twice is a no-op
+        scope.dispose();
+        assert_eq!(log.borrow().len(), 3);
+    }
+
+    #[test]
+    fn scope_can_be_reentered_later() {
+        let cx = Runtime::new();
+        let count = cx.create_signal(0);
+        let runs = Rc::new(Cell::new(0));
+
+        let scope = cx.create_root(|scope| scope);
+        scope.run(|| {
+            cx.create_effect({
+                let runs = runs.clone();
+                move || {
+                    count.get();
+                    runs.set(runs.get() + 1);
+                }
+            });
+        });
+
+        scope.dispose();
+        count.set(1);
+        assert_eq!(runs.get(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "signal has been disposed")]
+    fn disposed_signal_cannot_be_read() {
+        let cx = Runtime::new();
+        let (signal, scope) = cx.create_root(|scope| (cx.create_signal(0), scope));
+
+        scope.dispose();
+        signal.get();
+    }
+
+    #[test]
+    fn stale_handle_does_not_reach_a_reused_slot() {
+        let cx = Runtime::new();
+        let (old, scope) = cx.create_root(|scope| (cx.create_signal(1), scope));
+        scope.dispose();
+
+        let new = cx.create_signal("new");
+        // `new` took the slot of `old`
+        let graph = cx.graph();
+        assert_eq!(graph.signals.len(), 1);
+        assert_eq!(graph.signals[0].id, 0);
+
+        assert_eq!(old.try_get(), Err(ReactiveError::Disposed));
+        assert_eq!(old.try_set(2), Err(ReactiveError::Disposed));
+        assert_eq!(new.try_get(), Ok("new"));
+
+        cx.dispose();
+        assert_eq!(new.try_get(), Err(ReactiveError::Disposed));
+    }
+
+    #[test]
+    fn runtime_dispose_runs_cleanups_and_drops_values() {
+        let cx = Runtime::new();
+        let value = Rc::new(());
+        let cleaned = Rc::new(Cell::new(false));
+
+        cx.create_signal(value.clone());
+        cx.create_root(|_| {
+            let cleaned = cleaned.clone();
+            cx.on_cleanup(move || cleaned.set(true));
+        });
+        assert_eq!(Rc::strong_count(&value), 2);
+
+        cx.dispose();
+        assert!(cleaned.get());
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        // disposing twice is a no-op
+        cx.dispose();
+    }
+
+    #[test]
+    fn disposed_runtime_slot_is_reused_without_reaching_old_handles() {
+        let old = Runtime::new();
+        let count = old.create_signal(1);
+        old.dispose();
+
+        let new = Runtime::new();
+        new.create_signal(2);
+        assert_eq!(count.try_get(), Err(ReactiveError::Disposed));
+        assert!(old != new);
+    }
+}
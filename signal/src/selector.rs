@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{Runtime, Scope, Signal, SignalGet};
+
+/// Answers "is this key the selected one?" so that a change of the source
+/// only notifies readers of the previous and the new key.
+pub struct Selector<K> {
+    cx: Runtime,
+    scope: Scope,
+    current: Signal<Option<K>>,
+    keys: Signal<HashMap<K, KeyState>>,
+}
+
+struct KeyState {
+    selected: Signal<bool>,
+    // owns `selected`, so it can be disposed on its own
+    scope: Scope,
+    // scopes that asked for the key and are not disposed yet
+    users: usize,
+}
+
+impl<K> Clone for Selector<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for Selector<K> {}
+
+impl Runtime {
+    pub fn create_selector<K>(&self, source: impl SignalGet<K> + 'static) -> Selector<K>
+    where
+        K: Eq + Hash + Clone + 'static,
+    {
+        let cx = *self;
+
+        self.create_scope(move |scope| {
+            let current = cx.create_signal(None::<K>);
+            let keys = cx.create_signal(HashMap::<K, KeyState>::new());
+
+            cx.create_effect(move || {
+                let next = source.get();
+                let prev = current.with_untracked(|current| current.clone());
+
+                if prev.as_ref() == Some(&next) {
+                    return;
+                }
+
+                keys.with_untracked(|keys| {
+                    if let Some(prev) = prev.and_then(|prev| keys.get(&prev)) {
+                        prev.selected.set(false);
+                    }
+                    if let Some(next) = keys.get(&next) {
+                        next.selected.set(true);
+                    }
+                });
+                current.update(|current| *current = Some(next));
+            });
+
+            Selector {
+                cx,
+                scope,
+                current,
+                keys,
+            }
+        })
+    }
+}
+
+impl<K> Selector<K>
+where
+    K: Eq + Hash + Clone + 'static,
+{
+    /// Whether `key` is the current value of the source. The running effect
+    /// is only re-run when the answer for this key changes. The key is
+    /// forgotten once every scope that asked for it is disposed.
+    pub fn is_selected(&self, key: &K) -> bool {
+        let mut signal = None;
+        self.keys.update(|keys| {
+            if let Some(state) = keys.get_mut(key) {
+                state.users += 1;
+                signal = Some(state.selected);
+            }
+        });
+
+        let signal = signal.unwrap_or_else(|| {
+            let selected = self
+                .current
+                .with_untracked(|current| current.as_ref() == Some(key));
+            // owned by the selector, not by the effect asking
+            let (selected, scope) = self.scope.run(|| {
+                self.cx
+                    .create_scope(|scope| (self.cx.create_signal(selected), scope))
+            });
+            self.keys.update(|keys| {
+                keys.insert(
+                    key.clone(),
+                    KeyState {
+                        selected,
+                        scope,
+                        users: 1,
+                    },
+                );
+            });
+            selected
+        });
+
+        let keys = self.keys;
+        let key = key.clone();
+        self.cx.on_cleanup(move || {
+            let mut unused = None;
+            // the selector may have been disposed first
+            let _ = keys.try_update(|keys| {
+                if let Some(state) = keys.get_mut(&key) {
+                    state.users -= 1;
+                    if state.users == 0 {
+                        unused = keys.remove(&key);
+                    }
+                }
+            });
+
+            if let Some(state) = unused {
+                state.scope.dispose();
+            }
+        });
+
+        signal.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::Runtime;
+
+    #[test]
+    fn only_previous_and_next_keys_are_notified() {
+        let cx = Runtime::new();
+        let selected = cx.create_signal(1);
+        let is_selected = cx.create_selector(selected);
+        let runs = Rc::new(RefCell::new(Vec::new()));
+
+        for row in 0..5 {
+            let runs = runs.clone();
+            cx.create_effect(move || runs.borrow_mut().push((row, is_selected.is_selected(&row))));
+        }
+        runs.borrow_mut().clear();
+
+        selected.set(3);
+        runs.borrow_mut().sort();
+        assert_eq!(*runs.borrow(), [(1, false), (3, true)]);
+        runs.borrow_mut().clear();
+
+        // same value again notifies nobody
+        selected.set(3);
+        assert!(runs.borrow().is_empty());
+
+        // keys nobody asked for yet notify nobody either
+        selected.set(7);
+        assert_eq!(*runs.borrow(), [(3, false)]);
+        assert!(is_selected.is_selected(&7));
+    }
+
+    #[test]
+    fn keys_are_forgotten_with_the_scopes_that_asked() {
+        let cx = Runtime::new();
+        let selected = cx.create_signal(0);
+        let is_selected = cx.create_selector(selected);
+
+        let rows = (0..10)
+            .map(|row| {
+                cx.create_root(|scope| {
+                    cx.create_effect(move || {
+                        is_selected.is_selected(&(row % 5));
+                    });
+                    scope
+                })
+            })
+            .collect::<Vec<_>>();
+        selected.set(3);
+        assert_eq!(is_selected.keys.with_untracked(|keys| keys.len()), 5);
+
+        for row in rows {
+            row.dispose();
+        }
+        assert!(is_selected.keys.with_untracked(|keys| keys.is_empty()));
+    }
+}
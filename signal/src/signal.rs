@@ -0,0 +1,264 @@
+use std::marker::PhantomData;
+
+use crate::error::ReactiveError;
+use crate::runtime::{Runtime, SignalId};
+
+pub struct Signal<T> {
+    cx: Runtime,
+    id: SignalId,
+    ty: PhantomData<T>,
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Signal<T> {}
+
+impl<T> Signal<T> {
+    pub(crate) fn new(cx: Runtime, id: SignalId) -> Self {
+        Signal {
+            cx,
+            id,
+            ty: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn id(&self) -> SignalId {
+        self.id
+    }
+
+    pub fn runtime(&self) -> Runtime {
+        self.cx
+    }
+
+    /// Name shown for this node in [`Runtime::graph`] and trace events.
+    pub fn named(self, name: impl Into<String>) -> Self {
+        self.cx.state().set_signal_name(self.id, name.into());
+        self
+    }
+}
+
+impl<T> Signal<T>
+where
+    T: Clone + 'static,
+{
+    pub fn get(&self) -> T {
+        self.with(T::clone)
+    }
+
+    pub fn get_untracked(&self) -> T {
+        self.with_untracked(T::clone)
+    }
+
+    /// Same as [`Signal::get`], but returns an error instead of panicking
+    /// when the signal has been disposed.
+    pub fn try_get(&self) -> Result<T, ReactiveError> {
+        self.try_with(T::clone)
+    }
+}
+
+impl<T> Signal<T>
+where
+    T: 'static,
+{
+    /// Borrows the value for the duration of `f` instead of cloning it.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let state = self.cx.state();
+        state.track(self.id);
+
+        state.with_value(self.id, f)
+    }
+
+    pub fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.cx.state().with_value(self.id, f)
+    }
+
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, ReactiveError> {
+        let state = self.cx.try_state()?;
+        state.track(self.id);
+
+        state.try_with_value(self.id, f)
+    }
+
+    pub fn set(&self, value: T) {
+        self.update(|wrapper| *wrapper = value);
+    }
+
+    /// Same as [`Signal::set`], but returns the error if the signal has been
+    /// disposed or the update it started had to be aborted.
+    pub fn try_set(&self, value: T) -> Result<(), ReactiveError> {
+        self.try_update(|wrapper| *wrapper = value)
+    }
+
+    /// Mutates the value in place and notifies subscribers.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let state = self.cx.state();
+
+        // set value
+        state.update_value(self.id, f);
+
+        // notify subscribers
+        state.notify(self.id);
+        state.report(state.flush());
+    }
+
+    pub fn try_update(&self, f: impl FnOnce(&mut T)) -> Result<(), ReactiveError> {
+        let state = self.cx.try_state()?;
+
+        state.try_update_value(self.id, f)?;
+
+        state.notify(self.id);
+        state.flush()
+    }
+}
+
+/// Read half of a [`Signal`], see [`Signal::split`].
+pub struct ReadSignal<T> {
+    signal: Signal<T>,
+}
+
+/// Write half of a [`Signal`], see [`Signal::split`].
+pub struct WriteSignal<T> {
+    signal: Signal<T>,
+}
+
+impl<T> Clone for ReadSignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReadSignal<T> {}
+
+impl<T> Clone for WriteSignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WriteSignal<T> {}
+
+impl<T> Signal<T> {
+    /// Splits the signal into handles that can only read or only write it,
+    /// so a widget can be handed one without the other.
+    pub fn split(self) -> (ReadSignal<T>, WriteSignal<T>) {
+        (ReadSignal { signal: self }, WriteSignal { signal: self })
+    }
+
+    pub fn read_only(self) -> ReadSignal<T> {
+        ReadSignal { signal: self }
+    }
+}
+
+impl<T> ReadSignal<T>
+where
+    T: Clone + 'static,
+{
+    pub fn get(&self) -> T {
+        self.signal.get()
+    }
+
+    pub fn get_untracked(&self) -> T {
+        self.signal.get_untracked()
+    }
+
+    pub fn try_get(&self) -> Result<T, ReactiveError> {
+        self.signal.try_get()
+    }
+}
+
+impl<T> ReadSignal<T>
+where
+    T: 'static,
+{
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.signal.with(f)
+    }
+
+    pub fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.signal.with_untracked(f)
+    }
+
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, ReactiveError> {
+        self.signal.try_with(f)
+    }
+}
+
+impl<T> WriteSignal<T>
+where
+    T: 'static,
+{
+    pub fn set(&self, value: T) {
+        self.signal.set(value)
+    }
+
+    pub fn try_set(&self, value: T) -> Result<(), ReactiveError> {
+        self.signal.try_set(value)
+    }
+
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        self.signal.update(f)
+    }
+
+    pub fn try_update(&self, f: impl FnOnce(&mut T)) -> Result<(), ReactiveError> {
+        self.signal.try_update(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::Runtime;
+
+    // deliberately not `Clone`
+    struct Document {
+        lines: Vec<String>,
+    }
+
+    #[test]
+    fn with_and_update_work_without_clone() {
+        let cx = Runtime::new();
+        let document = cx.create_signal(Document { lines: Vec::new() });
+        let line_count = Rc::new(Cell::new(0));
+
+        cx.create_effect({
+            let line_count = line_count.clone();
+            move || line_count.set(document.with(|document| document.lines.len()))
+        });
+
+        document.update(|document| document.lines.push("first".to_string()));
+        document.update(|document| document.lines.push("second".to_string()));
+        assert_eq!(line_count.get(), 2);
+    }
+
+    #[test]
+    fn untracked_reads_do_not_subscribe() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let b = cx.create_signal(1);
+        let c = cx.create_signal(1);
+        let runs = Rc::new(Cell::new(0));
+
+        cx.create_effect({
+            let runs = runs.clone();
+            move || {
+                a.get();
+                b.get_untracked();
+                cx.untrack(|| c.get());
+                runs.set(runs.get() + 1);
+            }
+        });
+
+        b.set(2);
+        c.set(2);
+        assert_eq!(runs.get(), 1);
+
+        a.set(2);
+        assert_eq!(runs.get(), 2);
+    }
+}
@@ -0,0 +1,314 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::{Runtime, Scope, Signal};
+
+/// Change made to a [`SignalVec`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VecDiff<T> {
+    Insert { index: usize, value: T },
+    Remove { index: usize },
+    Move { from: usize, to: usize },
+    Update { index: usize, value: T },
+    Replace { values: Vec<T> },
+}
+
+type Listener<T> = Rc<dyn Fn(&VecDiff<T>)>;
+
+struct Listeners<T> {
+    next_id: usize,
+    listeners: Vec<(usize, Listener<T>)>,
+}
+
+/// Vec signal that reports every change as a [`VecDiff`], so consumers can
+/// patch their state instead of rebuilding it.
+pub struct SignalVec<T> {
+    cx: Runtime,
+    values: Signal<Vec<T>>,
+    listeners: Signal<Listeners<T>>,
+}
+
+impl<T> Clone for SignalVec<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SignalVec<T> {}
+
+impl Runtime {
+    pub fn create_signal_vec<T>(&self, values: Vec<T>) -> SignalVec<T>
+    where
+        T: Clone + 'static,
+    {
+        SignalVec {
+            cx: *self,
+            values: self.create_signal(values),
+            listeners: self.create_signal(Listeners {
+                next_id: 0,
+                listeners: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl<T> SignalVec<T>
+where
+    T: Clone + 'static,
+{
+    /// Reads the whole list, subscribing the running effect to every change.
+    pub fn with<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+        self.values.with(|values| f(values))
+    }
+
+    pub fn with_untracked<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+        self.values.with_untracked(|values| f(values))
+    }
+
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.with(|values| values.get(index).cloned())
+    }
+
+    pub fn len(&self) -> usize {
+        self.with(|values| values.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn push(&self, value: T) {
+        let index = self.values.with_untracked(Vec::len);
+        self.insert(index, value);
+    }
+
+    pub fn insert(&self, index: usize, value: T) {
+        self.apply(VecDiff::Insert { index, value });
+    }
+
+    pub fn remove(&self, index: usize) -> T {
+        let value = self.values.with_untracked(|values| values[index].clone());
+        self.apply(VecDiff::Remove { index });
+        value
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let len = self.values.with_untracked(Vec::len);
+        (len > 0).then(|| self.remove(len - 1))
+    }
+
+    pub fn move_item(&self, from: usize, to: usize) {
+        self.apply(VecDiff::Move { from, to });
+    }
+
+    pub fn set(&self, index: usize, value: T) {
+        self.apply(VecDiff::Update { index, value });
+    }
+
+    pub fn replace(&self, values: Vec<T>) {
+        self.apply(VecDiff::Replace { values });
+    }
+
+    pub fn clear(&self) {
+        self.replace(Vec::new());
+    }
+
+    /// Calls `f` with every change made from now on, until the current scope
+    /// is disposed.
+    pub fn on_diff(&self, f: impl Fn(&VecDiff<T>) + 'static) {
+        let listeners = self.listeners;
+
+        let id = listeners.with_untracked(|listeners| listeners.next_id);
+        listeners.update(|listeners| {
+            listeners.next_id += 1;
+            listeners.listeners.push((id, Rc::new(f)));
+        });
+
+        // the list may have been disposed first, then there is nothing to remove
+        self.cx.on_cleanup(move || {
+            let _ = listeners
+                .try_update(|listeners| listeners.listeners.retain(|(other, _)| *other != id));
+        });
+    }
+
+    /// Runs `child` for every item in its own scope. The scope of an item is
+    /// disposed when the item is removed or updated, and kept when it moves or
+    /// survives a [`SignalVec::replace`] with the same key.
+    pub fn for_each_keyed<K>(
+        &self,
+        key: impl Fn(&T) -> K + 'static,
+        child: impl Fn(&T) + 'static,
+    ) where
+        K: Eq + Hash + 'static,
+    {
+        let cx = self.cx;
+        let key = Rc::new(key);
+
+        cx.create_scope(move |container| {
+            let create = {
+                let key = key.clone();
+                move |value: &T| {
+                    let item = container.run(|| cx.create_scope(|item| item));
+                    item.run(|| child(value));
+                    (key(value), item)
+                }
+            };
+
+            let items = self.values.with_untracked(|values| {
+                values.iter().map(&create).collect::<Vec<(K, Scope)>>()
+            });
+            let items = RefCell::new(items);
+
+            self.on_diff(move |diff| {
+                let mut items = items.borrow_mut();
+
+                match diff {
+                    VecDiff::Insert { index, value } => items.insert(*index, create(value)),
+                    VecDiff::Remove { index } => items.remove(*index).1.dispose(),
+                    VecDiff::Move { from, to } => {
+                        let item = items.remove(*from);
+                        items.insert(*to, item);
+                    }
+                    VecDiff::Update { index, value } => {
+                        let item = std::mem::replace(&mut items[*index], create(value));
+                        item.1.dispose();
+                    }
+                    VecDiff::Replace { values } => {
+                        let mut old = HashMap::new();
+                        for (key, item) in items.drain(..) {
+                            if let Some(duplicate) = old.insert(key, item) {
+                                duplicate.dispose();
+                            }
+                        }
+
+                        *items = values
+                            .iter()
+                            .map(|value| match old.remove_entry(&key(value)) {
+                                Some(item) => item,
+                                None => create(value),
+                            })
+                            .collect();
+
+                        for item in old.into_values() {
+                            item.dispose();
+                        }
+                    }
+                }
+            });
+        });
+    }
+
+    fn apply(&self, diff: VecDiff<T>) {
+        self.cx.batch(|| {
+            self.values.update(|values| match &diff {
+                VecDiff::Insert { index, value } => values.insert(*index, value.clone()),
+                VecDiff::Remove { index } => {
+                    values.remove(*index);
+                }
+                VecDiff::Move { from, to } => {
+                    let value = values.remove(*from);
+                    values.insert(*to, value);
+                }
+                VecDiff::Update { index, value } => values[*index] = value.clone(),
+                VecDiff::Replace { values: new_values } => *values = new_values.clone(),
+            });
+
+            let listeners = self.listeners.with_untracked(|listeners| {
+                listeners
+                    .listeners
+                    .iter()
+                    .map(|(_, listener)| listener.clone())
+                    .collect::<Vec<_>>()
+            });
+
+            for listener in listeners {
+                listener(&diff);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::VecDiff;
+    use crate::Runtime;
+
+    #[test]
+    fn changes_are_reported_as_diffs() {
+        let cx = Runtime::new();
+        let list = cx.create_signal_vec(vec![1, 2]);
+        let diffs = Rc::new(RefCell::new(Vec::new()));
+
+        list.on_diff({
+            let diffs = diffs.clone();
+            move |diff| diffs.borrow_mut().push(diff.clone())
+        });
+
+        list.push(3);
+        list.move_item(2, 0);
+        list.set(1, 10);
+        assert_eq!(list.remove(2), 2);
+        list.clear();
+
+        assert_eq!(
+            *diffs.borrow(),
+            [
+                VecDiff::Insert { index: 2, value: 3 },
+                VecDiff::Move { from: 2, to: 0 },
+                VecDiff::Update {
+                    index: 1,
+                    value: 10
+                },
+                VecDiff::Remove { index: 2 },
+                VecDiff::Replace { values: vec![] },
+            ]
+        );
+    }
+
+    #[test]
+    fn keyed_children_are_disposed_with_their_items() {
+        let cx = Runtime::new();
+        let list = cx.create_signal_vec(vec!["a", "b"]);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let scope = cx.create_root(|scope| {
+            let log = log.clone();
+            list.for_each_keyed(
+                |item| *item,
+                move |item| {
+                    let item = item.to_string();
+                    log.borrow_mut().push(format!("+{}", item));
+                    let log = log.clone();
+                    cx.on_cleanup(move || log.borrow_mut().push(format!("-{}", item)));
+                },
+            );
+            scope
+        });
+        assert_eq!(*log.borrow(), ["+a", "+b"]);
+        log.borrow_mut().clear();
+
+        list.push("c");
+        list.move_item(2, 0);
+        list.remove(1);
+        assert_eq!(*log.borrow(), ["+c", "-a"]);
+        log.borrow_mut().clear();
+
+        // `b` survives because its key is still there
+        list.replace(vec!["b", "d"]);
+        assert_eq!(*log.borrow(), ["+d", "-c"]);
+        log.borrow_mut().clear();
+
+        scope.dispose();
+        log.borrow_mut().sort();
+        assert_eq!(*log.borrow(), ["-b", "-d"]);
+
+        // no listener is left behind
+        list.push("e");
+        assert_eq!(log.borrow().len(), 2);
+    }
+}
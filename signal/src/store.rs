@@ -0,0 +1,73 @@
+use std::ops::Deref;
+
+use crate::Runtime;
+
+/// Struct split into one signal per field, implemented with
+/// `#[derive(Store)]`.
+pub trait Store: Sized + 'static {
+    /// Generated `<Name>Store` struct holding the signals.
+    type Fields: Copy + 'static;
+
+    fn create_fields(cx: Runtime, value: Self) -> Self::Fields;
+
+    /// Reads every field, subscribing to all of them.
+    fn read(fields: &Self::Fields) -> Self;
+
+    /// Writes the fields whose value changed.
+    fn write(fields: &Self::Fields, value: Self);
+}
+
+/// Handle to a [`Store`]. Derefs to the generated fields struct, so
+/// `settings.theme.get()` only subscribes to `theme`.
+pub struct StoreSignal<T: Store> {
+    cx: Runtime,
+    fields: T::Fields,
+}
+
+impl<T: Store> Clone for StoreSignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Store> Copy for StoreSignal<T> {}
+
+impl<T: Store> Deref for StoreSignal<T> {
+    type Target = T::Fields;
+
+    fn deref(&self) -> &T::Fields {
+        &self.fields
+    }
+}
+
+impl Runtime {
+    pub fn create_store<T: Store>(&self, value: T) -> StoreSignal<T> {
+        StoreSignal {
+            cx: *self,
+            fields: T::create_fields(*self, value),
+        }
+    }
+}
+
+impl<T: Store> StoreSignal<T> {
+    /// Rebuilds the whole value, subscribing to every field.
+    pub fn get(&self) -> T {
+        T::read(&self.fields)
+    }
+
+    pub fn get_untracked(&self) -> T {
+        self.cx.untrack(|| T::read(&self.fields))
+    }
+
+    /// Writes the changed fields in one batch, so effects reading the other
+    /// fields do not rerun.
+    pub fn set(&self, value: T) {
+        self.cx.batch(|| T::write(&self.fields, value));
+    }
+
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let mut value = self.get_untracked();
+        f(&mut value);
+        self.set(value);
+    }
+}
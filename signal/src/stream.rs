@@ -0,0 +1,168 @@
+use std::cell::Cell;
+
+use futures::channel::mpsc;
+use futures::future::{AbortHandle, Abortable};
+use futures::{Stream, StreamExt};
+
+use crate::{ReactiveError, ReadSignal, Runtime, Signal};
+
+impl<T> Signal<T>
+where
+    T: Clone + 'static,
+{
+    /// Stream of every value the signal changes to from now on. It ends when
+    /// the current scope is disposed, and stops following the signal once
+    /// the stream is dropped.
+    pub fn to_stream(&self) -> impl Stream<Item = T> + Unpin {
+        let (sender, receiver) = mpsc::unbounded();
+        let signal = *self;
+        let first_run = Cell::new(true);
+        let cx = self.runtime();
+
+        // owns the sender, so disposing it closes the stream
+        cx.create_scope(|scope| {
+            cx.create_effect(move || {
+                if sender.is_closed() {
+                    scope.dispose();
+                    return;
+                }
+
+                let value = signal.get();
+                if !first_run.replace(false) {
+                    let _ = sender.unbounded_send(value);
+                }
+            });
+        });
+
+        receiver
+    }
+}
+
+impl Runtime {
+    /// Signal set to every item of `stream`, polled on the runtime's
+    /// executor. The task is aborted when the current scope is disposed.
+    pub fn signal_from_stream<T>(
+        &self,
+        stream: impl Stream<Item = T> + 'static,
+        initial: T,
+    ) -> ReadSignal<T>
+    where
+        T: 'static,
+    {
+        let signal = self.create_signal(initial);
+        let (abort, registration) = AbortHandle::new_pair();
+
+        let task = async move {
+            let mut stream = Box::pin(stream);
+
+            while let Some(value) = stream.next().await {
+                if signal.try_set(value) == Err(ReactiveError::Disposed) {
+                    break;
+                }
+            }
+        };
+        self.spawn_local(async move {
+            let _ = Abortable::new(task, registration).await;
+        });
+        self.on_cleanup(move || abort.abort());
+
+        signal.read_only()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use futures::channel::mpsc;
+    use futures::StreamExt;
+
+    use crate::{LocalExecutor, Runtime};
+
+    #[test]
+    fn signal_changes_are_streamed_until_the_scope_is_disposed() {
+        let cx = Runtime::new();
+        let executor = LocalExecutor::new();
+        cx.set_executor(executor.clone());
+        let count = cx.create_signal(0);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let scope = cx.create_root(|scope| {
+            let mut stream = count.to_stream();
+            let seen = seen.clone();
+            cx.spawn_local(async move {
+                while let Some(value) = stream.next().await {
+                    seen.borrow_mut().push(value);
+                }
+                seen.borrow_mut().push(-1);
+            });
+            scope
+        });
+
+        count.set(1);
+        count.set(2);
+        executor.run_until_stalled();
+        assert_eq!(*seen.borrow(), [1, 2]);
+
+        scope.dispose();
+        count.set(3);
+        assert_eq!(executor.run_until_stalled(), 0);
+        assert_eq!(*seen.borrow(), [1, 2, -1]);
+    }
+
+    #[test]
+    fn stream_items_drive_a_signal() {
+        let cx = Runtime::new();
+        let executor = LocalExecutor::new();
+        cx.set_executor(executor.clone());
+        let (sender, receiver) = mpsc::unbounded();
+
+        let latest = cx.signal_from_stream(receiver, "none");
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        cx.create_effect({
+            let seen = seen.clone();
+            move || seen.borrow_mut().push(latest.get())
+        });
+
+        sender.unbounded_send("a").unwrap();
+        sender.unbounded_send("b").unwrap();
+        executor.run_until_stalled();
+        assert_eq!(*seen.borrow(), ["none", "a", "b"]);
+
+        drop(sender);
+        assert_eq!(executor.run_until_stalled(), 0);
+    }
+
+    #[test]
+    fn dropping_the_stream_stops_its_effect() {
+        let cx = Runtime::new();
+        let count = cx.create_signal(0);
+        let effects = || cx.graph().effects.len();
+
+        let stream = count.to_stream();
+        assert_eq!(effects(), 1);
+
+        drop(stream);
+        count.set(1);
+        assert_eq!(effects(), 0);
+    }
+
+    #[test]
+    fn disposing_the_scope_aborts_the_stream_task() {
+        let cx = Runtime::new();
+        let executor = LocalExecutor::new();
+        cx.set_executor(executor.clone());
+        let (sender, receiver) = mpsc::unbounded::<i32>();
+
+        let scope = cx.create_root(|scope| {
+            cx.signal_from_stream(receiver, 0);
+            scope
+        });
+        assert_eq!(executor.run_until_stalled(), 1);
+
+        scope.dispose();
+        assert_eq!(executor.run_until_stalled(), 0);
+        assert!(sender.is_closed());
+    }
+}
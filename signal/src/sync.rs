@@ -0,0 +1,460 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread::{self, ThreadId};
+
+use crate::arena::{Arena, Key};
+use crate::error::ReactiveError;
+
+/// Runtime whose signals are `Send + Sync` and can be written from any thread.
+///
+/// Effects belong to the thread that created the runtime. Writes only queue
+/// the changed signals; their subscribers run on the owning thread the next
+/// time it calls [`SyncRuntime::run_pending`], e.g. from the message loop
+/// after the waker posted a message to it.
+pub struct SyncRuntime {
+    shared: Arc<Shared>,
+    effects: Effects,
+    max_iterations: Cell<usize>,
+}
+
+type Waker = Box<dyn Fn() + Send + Sync>;
+type Effects = Rc<RefCell<Arena<Rc<dyn Fn()>>>>;
+
+struct Shared {
+    owner: ThreadId,
+    next_signal: AtomicUsize,
+    running_effect: Mutex<Option<Key>>,
+    signal_subscribers: Mutex<HashMap<usize, HashSet<Key>>>,
+    effect_sources: Mutex<HashMap<Key, HashSet<usize>>>,
+    changed_signals: Mutex<BTreeSet<usize>>,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Default for SyncRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SyncRuntime {
+    pub fn new() -> Self {
+        SyncRuntime {
+            shared: Arc::new(Shared {
+                owner: thread::current().id(),
+                next_signal: AtomicUsize::new(0),
+                running_effect: Mutex::new(None),
+                signal_subscribers: Default::default(),
+                effect_sources: Default::default(),
+                changed_signals: Default::default(),
+                waker: Mutex::new(None),
+            }),
+            effects: Default::default(),
+            max_iterations: Cell::new(100),
+        }
+    }
+
+    pub fn create_signal<T>(&self, value: T) -> SyncSignal<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        SyncSignal {
+            shared: self.shared.clone(),
+            id: self.shared.next_signal.fetch_add(1, Ordering::Relaxed),
+            value: Arc::new(RwLock::new(value)),
+        }
+    }
+
+    /// Runs `f` now and again whenever a signal it read changes, until the
+    /// returned handle is disposed.
+    pub fn create_effect(&self, f: impl Fn() + 'static) -> SyncEffect {
+        let id = self.effects.borrow_mut().insert(Rc::new(f));
+
+        self.run_effect(id);
+
+        SyncEffect {
+            shared: self.shared.clone(),
+            effects: self.effects.clone(),
+            id,
+        }
+    }
+
+    /// Called from the writing thread whenever a write queues work for an
+    /// idle runtime, so the owning thread can be woken up to run it.
+    pub fn set_waker(&self, waker: impl Fn() + Send + Sync + 'static) {
+        *self.shared.waker.lock().unwrap() = Some(Box::new(waker));
+    }
+
+    pub fn set_max_iterations(&self, max_iterations: usize) {
+        self.max_iterations.set(max_iterations);
+    }
+
+    /// Runs the effects of every signal written since the last call,
+    /// including the ones written by those effects.
+    pub fn run_pending(&self) -> Result<(), ReactiveError> {
+        let max_iterations = self.max_iterations.get();
+        let mut runs = HashMap::<Key, usize>::new();
+
+        loop {
+            let changed = std::mem::take(&mut *self.shared.changed_signals.lock().unwrap());
+            if changed.is_empty() {
+                return Ok(());
+            }
+
+            let pending = {
+                let subs = self.shared.signal_subscribers.lock().unwrap();
+                changed
+                    .iter()
+                    .filter_map(|signal| subs.get(signal))
+                    .flatten()
+                    .copied()
+                    .collect::<BTreeSet<_>>()
+            };
+
+            for effect_id in pending {
+                let count = runs.entry(effect_id).or_default();
+                *count += 1;
+                if *count > max_iterations {
+                    self.shared.changed_signals.lock().unwrap().clear();
+                    return Err(ReactiveError::CycleDetected {
+                        iterations: max_iterations,
+                    });
+                }
+
+                self.run_effect(effect_id);
+            }
+        }
+    }
+
+    fn run_effect(&self, effect_id: Key) {
+        // disposed by an effect that ran before it
+        let Some(effect) = self.effects.borrow().get(effect_id).cloned() else {
+            return;
+        };
+
+        // dependencies are collected again on every run
+        self.shared.cleanup_sources(effect_id);
+
+        let prev_running_effect = self
+            .shared
+            .running_effect
+            .lock()
+            .unwrap()
+            .replace(effect_id);
+
+        // run effect
+        effect();
+
+        *self.shared.running_effect.lock().unwrap() = prev_running_effect;
+    }
+}
+
+impl Shared {
+    fn cleanup_sources(&self, effect_id: Key) {
+        let sources = self.effect_sources.lock().unwrap().remove(&effect_id);
+
+        if let Some(sources) = sources {
+            let mut subs = self.signal_subscribers.lock().unwrap();
+            for source in sources {
+                if let Some(effects) = subs.get_mut(&source) {
+                    effects.remove(&effect_id);
+                    if effects.is_empty() {
+                        subs.remove(&source);
+                    }
+                }
+            }
+        }
+    }
+
+    fn track(&self, id: usize) {
+        // effects only ever run on the owning thread
+        if thread::current().id() != self.owner {
+            return;
+        }
+
+        if let Some(running_effect) = *self.running_effect.lock().unwrap() {
+            let mut subs = self.signal_subscribers.lock().unwrap();
+            subs.entry(id).or_default().insert(running_effect);
+
+            let mut sources = self.effect_sources.lock().unwrap();
+            sources.entry(running_effect).or_default().insert(id);
+        }
+    }
+
+    fn notify(&self, id: usize) {
+        let was_idle = {
+            let mut changed = self.changed_signals.lock().unwrap();
+            let was_idle = changed.is_empty();
+            changed.insert(id);
+            was_idle
+        };
+
+        if was_idle {
+            if let Some(waker) = self.waker.lock().unwrap().as_ref() {
+                waker();
+            }
+        }
+    }
+}
+
+/// Handle to an effect created with [`SyncRuntime::create_effect`]. Unlike
+/// the effects of a [`Runtime`](crate::Runtime) there is no scope owning it,
+/// so it runs until it is disposed.
+pub struct SyncEffect {
+    shared: Arc<Shared>,
+    effects: Effects,
+    id: Key,
+}
+
+impl SyncEffect {
+    /// Stops the effect and unsubscribes it from its signals.
+    pub fn dispose(self) {
+        let effect = self.effects.borrow_mut().remove(self.id);
+        self.shared.cleanup_sources(self.id);
+        drop(effect);
+    }
+}
+
+/// Signal of a [`SyncRuntime`]. Clones are cheap and share the same value.
+pub struct SyncSignal<T> {
+    shared: Arc<Shared>,
+    id: usize,
+    value: Arc<RwLock<T>>,
+}
+
+impl<T> Clone for SyncSignal<T> {
+    fn clone(&self) -> Self {
+        SyncSignal {
+            shared: self.shared.clone(),
+            id: self.id,
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<T> SyncSignal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    pub fn get(&self) -> T {
+        self.with(T::clone)
+    }
+
+    pub fn get_untracked(&self) -> T {
+        self.with_untracked(T::clone)
+    }
+}
+
+impl<T> SyncSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.shared.track(self.id);
+
+        self.with_untracked(f)
+    }
+
+    pub fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.value.read().unwrap())
+    }
+
+    pub fn set(&self, value: T) {
+        self.update(|wrapper| *wrapper = value);
+    }
+
+    /// Mutates the value under the write lock, so concurrent updates are
+    /// never lost.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.value.write().unwrap());
+
+        self.shared.notify(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::thread;
+
+    use super::{SyncRuntime, SyncSignal};
+    use crate::{ReactiveError, SignalGet, SignalUpdate};
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    #[test]
+    fn concurrent_updates_are_not_lost() {
+        let cx = SyncRuntime::new();
+        let count = cx.create_signal(0);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        assert_send_sync(&count);
+
+        cx.create_effect({
+            let count = count.clone();
+            let seen = seen.clone();
+            move || seen.lock().unwrap().push(count.get())
+        });
+
+        thread::scope(|s| {
+            for _ in 0..8 {
+                let count = count.clone();
+                s.spawn(move || {
+                    for _ in 0..1000 {
+                        count.update(|count| *count += 1);
+                    }
+                });
+            }
+        });
+
+        // nothing runs until the owning thread asks for it
+        assert_eq!(*seen.lock().unwrap(), [0]);
+
+        cx.run_pending().unwrap();
+        assert_eq!(*seen.lock().unwrap(), [0, 8000]);
+    }
+
+    #[test]
+    fn writers_to_different_signals_run_each_effect_once() {
+        let cx = SyncRuntime::new();
+        let signals = (0..4).map(|_| cx.create_signal(0)).collect::<Vec<_>>();
+        let sums = Arc::new(Mutex::new(Vec::new()));
+
+        cx.create_effect({
+            let signals = signals.clone();
+            let sums = sums.clone();
+            move || {
+                let sum: i32 = signals.iter().map(SyncSignal::get).sum();
+                sums.lock().unwrap().push(sum);
+            }
+        });
+
+        thread::scope(|s| {
+            for (i, signal) in signals.iter().enumerate() {
+                s.spawn(move || signal.set(i as i32 + 1));
+            }
+        });
+
+        cx.run_pending().unwrap();
+        assert_eq!(*sums.lock().unwrap(), [0, 10]);
+    }
+
+    #[test]
+    fn waker_is_called_once_per_idle_period() {
+        let cx = SyncRuntime::new();
+        let count = cx.create_signal(0);
+        let wakes = Arc::new(AtomicUsize::new(0));
+
+        cx.set_waker({
+            let wakes = wakes.clone();
+            move || {
+                wakes.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let (tx, rx) = mpsc::channel();
+        let writer = thread::spawn({
+            let count = count.clone();
+            move || {
+                for i in 0..100 {
+                    count.set(i);
+                }
+                tx.send(()).unwrap();
+            }
+        });
+
+        rx.recv().unwrap();
+        writer.join().unwrap();
+        assert_eq!(wakes.load(Ordering::SeqCst), 1);
+
+        cx.run_pending().unwrap();
+        count.set(7);
+        assert_eq!(wakes.load(Ordering::SeqCst), 2);
+        assert_eq!(count.get(), 7);
+    }
+
+    #[test]
+    fn reads_from_other_threads_do_not_subscribe() {
+        let cx = SyncRuntime::new();
+        let a = cx.create_signal(1);
+        let b = cx.create_signal(1);
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        cx.create_effect({
+            let a = a.clone();
+            let b = b.clone();
+            let runs = runs.clone();
+            move || {
+                a.get();
+                // the same read from a worker is not a dependency
+                let b = b.clone();
+                thread::spawn(move || b.get()).join().unwrap();
+                runs.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        b.set(2);
+        cx.run_pending().unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        a.set(2);
+        cx.run_pending().unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn effect_writing_its_own_dependency_is_reported_as_cycle() {
+        let cx = SyncRuntime::new();
+        cx.set_max_iterations(5);
+        let count = cx.create_signal(0);
+
+        cx.create_effect({
+            let count = count.clone();
+            move || count.set(count.get() + 1)
+        });
+
+        assert_eq!(
+            cx.run_pending(),
+            Err(ReactiveError::CycleDetected { iterations: 5 })
+        );
+        assert_eq!(cx.run_pending(), Ok(()));
+    }
+
+    #[test]
+    fn disposed_effects_are_unsubscribed() {
+        let cx = SyncRuntime::new();
+        let count = cx.create_signal(0);
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let effect = cx.create_effect({
+            let count = count.clone();
+            let runs = runs.clone();
+            move || {
+                count.get();
+                runs.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        effect.dispose();
+
+        count.set(1);
+        cx.run_pending().unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(cx.shared.signal_subscribers.lock().unwrap().is_empty());
+        assert!(cx.shared.effect_sources.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn sync_signals_implement_the_signal_traits() {
+        fn double(count: &(impl SignalGet<i32> + SignalUpdate<i32>)) {
+            let value = count.get();
+            count.update(|count| *count = value * 2);
+        }
+
+        let cx = SyncRuntime::new();
+        let count = cx.create_signal(21);
+        double(&count);
+        assert_eq!(count.get(), 42);
+    }
+}
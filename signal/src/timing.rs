@@ -0,0 +1,212 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::clock::Clock;
+use crate::{ReactiveError, ReadSignal, Runtime, Signal, SignalGet};
+
+impl Runtime {
+    /// Follows `source`, but only takes its value once it has not changed
+    /// for `delay`.
+    pub fn debounced<T>(
+        &self,
+        source: impl SignalGet<T> + 'static,
+        delay: Duration,
+    ) -> ReadSignal<T>
+    where
+        T: Clone + 'static,
+    {
+        let clock = self.clock();
+        let output = self.create_signal(self.untrack(|| source.get()));
+        // bumped by every change, so only the last scheduled timer writes
+        let generation = Rc::new(Cell::new(0_u64));
+        let first_run = Cell::new(true);
+
+        self.create_effect(move || {
+            let value = source.get();
+            if first_run.replace(false) {
+                return;
+            }
+
+            let current = generation.get() + 1;
+            generation.set(current);
+
+            let generation = generation.clone();
+            clock.schedule(
+                clock.now() + delay,
+                Box::new(move || {
+                    if generation.get() == current {
+                        // the owner may have been disposed in the meantime
+                        let _ = output.try_set(value);
+                    }
+                }),
+            );
+        });
+
+        output.read_only()
+    }
+
+    /// Follows `source`, but changes at most once per `interval`. The first
+    /// change goes through right away and the last one is never lost.
+    pub fn throttled<T>(
+        &self,
+        source: impl SignalGet<T> + 'static,
+        interval: Duration,
+    ) -> ReadSignal<T>
+    where
+        T: Clone + 'static,
+    {
+        let clock = self.clock();
+        let output = self.create_signal(self.untrack(|| source.get()));
+        let last_change = Rc::new(Cell::new(None::<Duration>));
+        let pending = Rc::new(RefCell::new(None::<T>));
+        let first_run = Cell::new(true);
+
+        self.create_effect(move || {
+            let value = source.get();
+            if first_run.replace(false) {
+                return;
+            }
+
+            let now = clock.now();
+            let next_allowed = last_change.get().map(|last| last + interval);
+
+            match next_allowed {
+                Some(at) if now < at => {
+                    // a timer is already waiting if there was a pending value
+                    if pending.replace(Some(value)).is_none() {
+                        let clock = clock.clone();
+                        let last_change = last_change.clone();
+                        let pending = pending.clone();
+
+                        clock.clone().schedule(
+                            at,
+                            Box::new(move || {
+                                if let Some(value) = pending.take() {
+                                    last_change.set(Some(clock.now()));
+                                    let _ = output.try_set(value);
+                                }
+                            }),
+                        );
+                    }
+                }
+                _ => {
+                    last_change.set(Some(now));
+                    output.set(value);
+                }
+            }
+        });
+
+        output.read_only()
+    }
+
+    /// Counts up every `period`, until the current scope is disposed.
+    ///
+    /// # Panics
+    ///
+    /// If `period` is zero, as the timer would be due again right away,
+    /// forever.
+    pub fn interval(&self, period: Duration) -> ReadSignal<u64> {
+        assert!(!period.is_zero(), "interval period is zero");
+        let clock = self.clock();
+        let ticks = self.create_signal(0);
+
+        schedule_tick(clock.clone(), clock.now() + period, period, ticks);
+
+        ticks.read_only()
+    }
+}
+
+fn schedule_tick(clock: Rc<dyn Clock>, at: Duration, period: Duration, ticks: Signal<u64>) {
+    clock.clone().schedule(
+        at,
+        Box::new(move || {
+            if ticks.try_update(|ticks| *ticks += 1) != Err(ReactiveError::Disposed) {
+                schedule_tick(clock, at + period, period, ticks);
+            }
+        }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use crate::{Runtime, VirtualClock};
+
+    const MS: Duration = Duration::from_millis(1);
+
+    fn setup() -> (Runtime, VirtualClock) {
+        let cx = Runtime::new();
+        let clock = VirtualClock::new();
+        cx.set_clock(clock.clone());
+        (cx, clock)
+    }
+
+    #[test]
+    fn debounced_waits_for_a_quiet_period() {
+        let (cx, clock) = setup();
+        let pointer = cx.create_signal(0);
+        let debounced = cx.debounced(pointer, 100 * MS);
+
+        for x in 1..=5 {
+            pointer.set(x);
+            clock.advance(50 * MS);
+        }
+        assert_eq!(debounced.get(), 0);
+
+        clock.advance(50 * MS);
+        assert_eq!(debounced.get(), 5);
+    }
+
+    #[test]
+    fn throttled_keeps_the_first_and_last_change() {
+        let (cx, clock) = setup();
+        let pointer = cx.create_signal(0);
+        let throttled = cx.throttled(pointer, 100 * MS);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        cx.create_effect({
+            let seen = seen.clone();
+            move || seen.borrow_mut().push((clock_ms(&cx), throttled.get()))
+        });
+
+        // a change every 30ms for 300ms
+        for x in 1..=10 {
+            pointer.set(x);
+            clock.advance(30 * MS);
+        }
+        clock.advance(200 * MS);
+
+        assert_eq!(
+            *seen.borrow(),
+            [(0, 0), (0, 1), (100, 4), (200, 7), (300, 10)]
+        );
+    }
+
+    #[test]
+    fn interval_stops_with_its_scope() {
+        let (cx, clock) = setup();
+        let (ticks, scope) = cx.create_root(|scope| (cx.interval(10 * MS), scope));
+
+        clock.advance(35 * MS);
+        assert_eq!(ticks.get(), 3);
+
+        scope.dispose();
+        clock.advance(100 * MS);
+        assert!(ticks.try_get().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "interval period is zero")]
+    fn interval_rejects_a_zero_period() {
+        let (cx, _clock) = setup();
+        cx.interval(Duration::ZERO);
+    }
+
+    fn clock_ms(cx: &Runtime) -> u128 {
+        cx.clock().now().as_millis()
+    }
+}
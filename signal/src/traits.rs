@@ -0,0 +1,188 @@
+//! Traits shared by signals, memos and plain closures, so APIs can accept
+//! anything readable (`impl SignalGet<T>`) or writable (`impl SignalSet<T>`).
+
+use crate::{HistorySignal, Memo, ReadSignal, Signal, SyncSignal, WriteSignal};
+
+pub trait SignalGet<T> {
+    fn get(&self) -> T;
+}
+
+pub trait SignalGetUntracked<T> {
+    fn get_untracked(&self) -> T;
+}
+
+pub trait SignalWith<T> {
+    fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R;
+}
+
+pub trait SignalWithUntracked<T> {
+    fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R;
+}
+
+pub trait SignalSet<T> {
+    fn set(&self, value: T);
+}
+
+pub trait SignalUpdate<T> {
+    fn update(&self, f: impl FnOnce(&mut T));
+}
+
+impl<T, F> SignalGet<T> for F
+where
+    F: Fn() -> T,
+{
+    fn get(&self) -> T {
+        self()
+    }
+}
+
+impl<T, F> SignalWith<T> for F
+where
+    F: Fn() -> T,
+{
+    fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self())
+    }
+}
+
+macro_rules! impl_read_traits {
+    ($($ty:ident),*) => {
+        $(
+            impl<T: Clone + 'static> SignalGet<T> for $ty<T> {
+                fn get(&self) -> T {
+                    $ty::get(self)
+                }
+            }
+
+            impl<T: Clone + 'static> SignalGetUntracked<T> for $ty<T> {
+                fn get_untracked(&self) -> T {
+                    $ty::get_untracked(self)
+                }
+            }
+
+            impl<T: 'static> SignalWith<T> for $ty<T> {
+                fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+                    $ty::with(self, f)
+                }
+            }
+
+            impl<T: 'static> SignalWithUntracked<T> for $ty<T> {
+                fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+                    $ty::with_untracked(self, f)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_write_traits {
+    ($($ty:ident),*) => {
+        $(
+            impl<T: 'static> SignalSet<T> for $ty<T> {
+                fn set(&self, value: T) {
+                    $ty::set(self, value)
+                }
+            }
+
+            impl<T: 'static> SignalUpdate<T> for $ty<T> {
+                fn update(&self, f: impl FnOnce(&mut T)) {
+                    $ty::update(self, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_read_traits!(Signal, ReadSignal, Memo, HistorySignal);
+impl_write_traits!(Signal, WriteSignal);
+
+// recording a snapshot needs `T: Clone`
+impl<T: Clone + 'static> SignalSet<T> for HistorySignal<T> {
+    fn set(&self, value: T) {
+        HistorySignal::set(self, value)
+    }
+}
+
+impl<T: Clone + 'static> SignalUpdate<T> for HistorySignal<T> {
+    fn update(&self, f: impl FnOnce(&mut T)) {
+        HistorySignal::update(self, f)
+    }
+}
+
+// the value of a sync signal is shared between threads
+impl<T: Clone + Send + Sync + 'static> SignalGet<T> for SyncSignal<T> {
+    fn get(&self) -> T {
+        SyncSignal::get(self)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> SignalGetUntracked<T> for SyncSignal<T> {
+    fn get_untracked(&self) -> T {
+        SyncSignal::get_untracked(self)
+    }
+}
+
+impl<T: Send + Sync + 'static> SignalWith<T> for SyncSignal<T> {
+    fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        SyncSignal::with(self, f)
+    }
+}
+
+impl<T: Send + Sync + 'static> SignalWithUntracked<T> for SyncSignal<T> {
+    fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        SyncSignal::with_untracked(self, f)
+    }
+}
+
+impl<T: Send + Sync + 'static> SignalSet<T> for SyncSignal<T> {
+    fn set(&self, value: T) {
+        SyncSignal::set(self, value)
+    }
+}
+
+impl<T: Send + Sync + 'static> SignalUpdate<T> for SyncSignal<T> {
+    fn update(&self, f: impl FnOnce(&mut T)) {
+        SyncSignal::update(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SignalGet, SignalSet};
+    use crate::Runtime;
+
+    fn label(text: impl SignalGet<String>) -> String {
+        format!("[{}]", text.get())
+    }
+
+    fn reset(counter: &impl SignalSet<i32>) {
+        counter.set(0);
+    }
+
+    #[test]
+    fn signals_memos_and_closures_are_readable() {
+        let cx = Runtime::new();
+        let name = cx.create_signal("name".to_string());
+        let upper = cx.create_memo(move || name.get().to_uppercase());
+        let (read, write) = name.split();
+
+        assert_eq!(label(name), "[name]");
+        assert_eq!(label(read), "[name]");
+        assert_eq!(label(upper), "[NAME]");
+        assert_eq!(label(move || format!("{}!", read.get())), "[name!]");
+
+        write.set("other".to_string());
+        assert_eq!(label(upper), "[OTHER]");
+    }
+
+    #[test]
+    fn signals_and_write_handles_are_writable() {
+        let cx = Runtime::new();
+        let count = cx.create_signal(5);
+        let (read, write) = cx.create_signal(5).split();
+
+        reset(&count);
+        reset(&write);
+        assert_eq!((count.get(), read.get()), (0, 0));
+    }
+}
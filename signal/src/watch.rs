@@ -0,0 +1,152 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{Runtime, Scope};
+
+impl Runtime {
+    /// Runs `callback` whenever a signal read by `deps` changes, with the new
+    /// and previous values of `deps` and what the callback returned last
+    /// time. Reads inside `callback` are not tracked. Unless `immediate` is
+    /// set, the first run only records the initial value.
+    ///
+    /// Dispose the returned scope to stop watching.
+    pub fn watch<W, R>(
+        &self,
+        deps: impl Fn() -> W + 'static,
+        callback: impl Fn(&W, Option<&W>, Option<R>) -> R + 'static,
+        immediate: bool,
+    ) -> Scope
+    where
+        W: 'static,
+        R: 'static,
+    {
+        let cx = *self;
+
+        self.create_scope(move |scope| {
+            let last = RefCell::new(None::<(W, Option<R>)>);
+
+            cx.create_effect(move || {
+                let value = deps();
+
+                let last = &mut *last.borrow_mut();
+                let (prev_value, prev_result) = match last.take() {
+                    Some((prev_value, prev_result)) => (Some(prev_value), prev_result),
+                    None if !immediate => {
+                        *last = Some((value, None));
+                        return;
+                    }
+                    None => (None, None),
+                };
+
+                let result = cx.untrack(|| callback(&value, prev_value.as_ref(), prev_result));
+                *last = Some((value, Some(result)));
+            });
+
+            scope
+        })
+    }
+
+    /// Wraps `f` so that only `deps` is tracked, e.g.
+    /// `cx.create_effect(cx.on(move || count.get(), |count, prev| ..))`.
+    /// `f` receives the value of `deps` and its value on the previous run.
+    pub fn on<W, R>(
+        &self,
+        deps: impl Fn() -> W + 'static,
+        f: impl Fn(&W, Option<&W>) -> R + 'static,
+    ) -> impl Fn() -> R + 'static
+    where
+        W: 'static,
+    {
+        let cx = *self;
+        let prev = Rc::new(RefCell::new(None::<W>));
+
+        move || {
+            let value = deps();
+            let prev_value = prev.borrow_mut().take();
+
+            let result = cx.untrack(|| f(&value, prev_value.as_ref()));
+            *prev.borrow_mut() = Some(value);
+
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::Runtime;
+
+    #[test]
+    fn watch_reports_previous_value_and_result() {
+        let cx = Runtime::new();
+        let count = cx.create_signal(1);
+        let other = cx.create_signal(0);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+
+        let watcher = cx.watch(
+            move || count.get(),
+            {
+                let calls = calls.clone();
+                move |new, old, prev_result: Option<i32>| {
+                    // not a dependency
+                    other.get();
+                    calls.borrow_mut().push((*new, old.copied(), prev_result));
+                    new * 10
+                }
+            },
+            false,
+        );
+        assert!(calls.borrow().is_empty());
+
+        count.set(2);
+        other.set(1);
+        count.set(3);
+        assert_eq!(*calls.borrow(), [(2, Some(1), None), (3, Some(2), Some(20))]);
+
+        watcher.dispose();
+        count.set(4);
+        assert_eq!(calls.borrow().len(), 2);
+    }
+
+    #[test]
+    fn immediate_watch_runs_on_creation() {
+        let cx = Runtime::new();
+        let count = cx.create_signal(1);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+
+        cx.watch(
+            move || count.get(),
+            {
+                let calls = calls.clone();
+                move |new, old, _: Option<()>| calls.borrow_mut().push((*new, old.copied()))
+            },
+            true,
+        );
+        count.set(2);
+
+        assert_eq!(*calls.borrow(), [(1, None), (2, Some(1))]);
+    }
+
+    #[test]
+    fn on_only_tracks_explicit_dependencies() {
+        let cx = Runtime::new();
+        let a = cx.create_signal(1);
+        let b = cx.create_signal(1);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+
+        cx.create_effect(cx.on(move || a.get(), {
+            let calls = calls.clone();
+            move |a, prev| calls.borrow_mut().push((*a, prev.copied(), b.get()))
+        }));
+
+        b.set(2);
+        a.set(2);
+        assert_eq!(*calls.borrow(), [(1, None, 1), (2, Some(1), 2)]);
+
+        let doubled = cx.create_memo(cx.on(move || a.get(), |a, _| a * 2));
+        a.set(5);
+        assert_eq!(doubled.get(), 10);
+    }
+}
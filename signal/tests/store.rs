@@ -0,0 +1,134 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use signal::{Runtime, Store};
+
+#[derive(Store, Debug, Clone, PartialEq)]
+struct Settings {
+    theme: String,
+    #[store(nested)]
+    window: Window,
+    recent: Vec<String>,
+}
+
+#[derive(Store, Debug, Clone, PartialEq)]
+struct Window {
+    width: u32,
+    height: u32,
+}
+
+fn settings() -> Settings {
+    Settings {
+        theme: "dark".to_string(),
+        window: Window {
+            width: 800,
+            height: 600,
+        },
+        recent: vec!["a.png".to_string()],
+    }
+}
+
+#[test]
+fn effects_only_rerun_for_fields_they_read() {
+    let cx = Runtime::new();
+    let store = cx.create_store(settings());
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    cx.create_effect({
+        let log = log.clone();
+        move || {
+            log.borrow_mut()
+                .push(format!("theme {}", store.theme.get()))
+        }
+    });
+    cx.create_effect({
+        let log = log.clone();
+        move || {
+            log.borrow_mut()
+                .push(format!("width {}", store.window.width.get()))
+        }
+    });
+    cx.create_effect({
+        let log = log.clone();
+        move || {
+            log.borrow_mut()
+                .push(format!("recent {}", store.recent.len()))
+        }
+    });
+    log.borrow_mut().clear();
+
+    store.window.height.set(700);
+    store.window.width.set(1024);
+    store.recent.push("b.png".to_string());
+    store.theme.set("light".to_string());
+
+    assert_eq!(*log.borrow(), ["width 1024", "recent 2", "theme light"]);
+}
+
+#[test]
+fn updates_only_write_changed_fields() {
+    let cx = Runtime::new();
+    let store = cx.create_store(settings());
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    cx.create_effect({
+        let log = log.clone();
+        move || {
+            log.borrow_mut()
+                .push(format!("theme {}", store.theme.get()))
+        }
+    });
+    cx.create_effect({
+        let log = log.clone();
+        move || {
+            log.borrow_mut()
+                .push(format!("height {}", store.window.height.get()))
+        }
+    });
+    store.recent.on_diff({
+        let log = log.clone();
+        move |_| log.borrow_mut().push("recent diff".to_string())
+    });
+    log.borrow_mut().clear();
+
+    store.update(|settings| settings.window.width = 1024);
+    store.set(settings());
+
+    assert!(log.borrow().is_empty());
+    assert_eq!(store.window.width.get_untracked(), 800);
+}
+
+#[test]
+fn whole_value_round_trips() {
+    let cx = Runtime::new();
+    let store = cx.create_store(settings());
+    let runs = Rc::new(RefCell::new(0));
+
+    cx.create_effect({
+        let runs = runs.clone();
+        move || {
+            store.get();
+            *runs.borrow_mut() += 1;
+        }
+    });
+
+    store.update(|settings| {
+        settings.theme = "light".to_string();
+        settings.window.width = 1024;
+        settings.recent.clear();
+    });
+
+    // one batch for every field
+    assert_eq!(*runs.borrow(), 2);
+    assert_eq!(
+        store.get_untracked(),
+        Settings {
+            theme: "light".to_string(),
+            window: Window {
+                width: 1024,
+                height: 600,
+            },
+            recent: vec![],
+        }
+    );
+}
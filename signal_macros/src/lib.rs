@@ -0,0 +1,187 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Field, Fields, GenericArgument, PathArguments,
+    Type,
+};
+
+/// Derives `signal::Store`, generating a `<Name>Store` struct with one signal
+/// per field, so effects only subscribe to the fields they read.
+///
+/// - `Vec<T>` fields become a `SignalVec<T>`.
+/// - Fields marked `#[store(nested)]` become the store of their own type,
+///   which must derive `Store` too.
+/// - Every other field becomes a `Signal<T>`.
+///
+/// Writing the whole value only sets the fields that changed, so every field
+/// type has to implement `PartialEq`.
+#[proc_macro_derive(Store, attributes(store))]
+pub fn derive_store(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum Kind<'a> {
+    Signal,
+    Vec(&'a Type),
+    Nested,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`Store` can not be derived for generic types",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "`Store` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`Store` can only be derived for structs",
+            ))
+        }
+    };
+
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let store = format_ident!("{}Store", ident);
+
+    let mut definitions = Vec::new();
+    let mut creates = Vec::new();
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+
+    for field in fields {
+        let vis = &field.vis;
+        let name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+
+        match kind(field)? {
+            Kind::Signal => {
+                definitions.push(quote!(#vis #name: ::signal::Signal<#ty>));
+                creates.push(quote!(#name: cx.create_signal(value.#name)));
+                reads.push(quote!(#name: fields.#name.get()));
+                writes.push(quote! {
+                    if fields.#name.with_untracked(|current| *current != value.#name) {
+                        fields.#name.set(value.#name);
+                    }
+                });
+            }
+            Kind::Vec(item) => {
+                definitions.push(quote!(#vis #name: ::signal::SignalVec<#item>));
+                creates.push(quote!(#name: cx.create_signal_vec(value.#name)));
+                reads.push(quote!(#name: fields.#name.with(|items| items.to_vec())));
+                writes.push(quote! {
+                    if fields.#name.with_untracked(|items| items != value.#name.as_slice()) {
+                        fields.#name.replace(value.#name);
+                    }
+                });
+            }
+            Kind::Nested => {
+                definitions.push(quote!(#vis #name: <#ty as ::signal::Store>::Fields));
+                creates.push(quote!(#name: ::signal::Store::create_fields(cx, value.#name)));
+                reads.push(quote!(#name: ::signal::Store::read(&fields.#name)));
+                writes.push(quote!(::signal::Store::write(&fields.#name, value.#name);));
+            }
+        }
+    }
+
+    let doc = format!("Per-field signals of [`{}`].", ident);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis struct #store {
+            #(#definitions,)*
+        }
+
+        impl ::core::clone::Clone for #store {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl ::core::marker::Copy for #store {}
+
+        impl ::signal::Store for #ident {
+            type Fields = #store;
+
+            #[allow(unused_variables)]
+            fn create_fields(cx: ::signal::Runtime, value: Self) -> #store {
+                #store {
+                    #(#creates,)*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn read(fields: &#store) -> Self {
+                #ident {
+                    #(#reads,)*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn write(fields: &#store, value: Self) {
+                #(#writes)*
+            }
+        }
+    })
+}
+
+fn kind(field: &Field) -> syn::Result<Kind<'_>> {
+    let mut nested = false;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("store"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nested") {
+                nested = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `nested`"))
+            }
+        })?;
+    }
+
+    if nested {
+        return Ok(Kind::Nested);
+    }
+
+    Ok(vec_item(&field.ty).map_or(Kind::Signal, Kind::Vec))
+}
+
+/// `T` of a `Vec<T>` field.
+fn vec_item(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(item) if args.args.len() == 1 => Some(item),
+        _ => None,
+    }
+}